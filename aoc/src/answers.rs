@@ -0,0 +1,425 @@
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    panic::{self, AssertUnwindSafe},
+    path::Path,
+    process::ExitCode,
+    time::{Duration, Instant},
+};
+
+use crate::solution::{InputKind, Part, Solution, EACH_PART};
+
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+pub const EACH_INPUT_KIND: [InputKind; 2] = [InputKind::Sample, InputKind::Puzzle];
+
+/// The known answers to a day's puzzle, kept in an `answers.toml` next to
+/// its input files:
+///
+/// ```toml
+/// [sample]
+/// part1 = "24"
+/// part2 = 93
+///
+/// [puzzle]
+/// part1 = "618"
+/// ```
+///
+/// Answers may be written as strings or integers. Anything missing is
+/// unknown, so a day can be checked before it has been solved.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Answers {
+    expected: HashMap<(InputKind, Part), String>,
+}
+
+impl Answers {
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let table: toml::Table = text.parse().map_err(|e| format!("{}", e))?;
+
+        let mut answers = Self::default();
+        for (section, parts) in &table {
+            let kind: InputKind = section.parse()?;
+            let parts = parts
+                .as_table()
+                .ok_or_else(|| format!("[{}] is not a table", section))?;
+            for (key, value) in parts {
+                let part = match key.as_str() {
+                    "part1" => Part::One,
+                    "part2" => Part::Two,
+                    _ => return Err(format!("'{}' in [{}] is not part1 or part2", key, section)),
+                };
+                let answer = match value {
+                    toml::Value::String(s) => s.clone(),
+                    toml::Value::Integer(i) => i.to_string(),
+                    _ => return Err(format!("{}.{} is not a string or integer", section, key)),
+                };
+                answers.insert(kind, part, answer);
+            }
+        }
+        Ok(answers)
+    }
+
+    /// Reads the answers file in `directory`, which may not exist yet.
+    pub fn load(directory: &str) -> Result<Self, String> {
+        let path = Path::new(directory).join(ANSWERS_FILE);
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let text = std::fs::read_to_string(&path).map_err(|e| format!("{}", e))?;
+        Self::parse(&text).map_err(|e| format!("{}: {}", path.display(), e))
+    }
+
+    pub fn expected(&self, kind: InputKind, part: Part) -> Option<&str> {
+        self.expected.get(&(kind, part)).map(|s| s.as_str())
+    }
+
+    pub fn insert(&mut self, kind: InputKind, part: Part, answer: String) {
+        self.expected.insert((kind, part), answer);
+    }
+
+    pub fn verdict(&self, kind: InputKind, part: Part, answer: &str) -> Verdict {
+        match self.expected(kind, part) {
+            Some(expected) if expected == answer => Verdict::Pass,
+            Some(_) => Verdict::Fail,
+            None => Verdict::Unknown,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail,
+    Unknown,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Pass => f.write_str("PASS"),
+            Verdict::Fail => f.write_str("FAIL"),
+            Verdict::Unknown => f.write_str("UNKNOWN"),
+        }
+    }
+}
+
+/// The outcome of solving one part against one input.
+#[derive(Debug, Clone)]
+pub struct Check {
+    pub kind: InputKind,
+    pub part: Part,
+    /// The answer given, or why there is none: the input couldn't be parsed
+    /// or the solution panicked.
+    pub answer: Result<String, String>,
+    pub expected: Option<String>,
+    pub elapsed: Duration,
+    pub verdict: Verdict,
+}
+
+/// Every [`Check`] run for one day.
+#[derive(Debug, Clone)]
+pub struct Report {
+    pub year: u32,
+    pub day: u32,
+    pub checks: Vec<Check>,
+    /// Why the answers file couldn't be read, in which case every answer is
+    /// unknown and the report fails.
+    pub answers_error: Option<String>,
+}
+
+impl Report {
+    pub fn count(&self, verdict: Verdict) -> usize {
+        self.checks.iter().filter(|c| c.verdict == verdict).count()
+    }
+
+    pub fn passed(&self) -> bool {
+        self.answers_error.is_none() && self.count(Verdict::Fail) == 0
+    }
+
+    pub fn exit_code(&self) -> ExitCode {
+        if self.passed() {
+            ExitCode::SUCCESS
+        } else {
+            ExitCode::FAILURE
+        }
+    }
+}
+
+// Pictures are reduced to their first line to keep the table readable, and
+// printed in full afterwards
+fn cell(answer: Option<&str>) -> String {
+    match answer {
+        Some(a) if a.contains('\n') => format!("{}...", a.lines().next().unwrap_or("")),
+        Some(a) => a.to_string(),
+        None => "-".to_string(),
+    }
+}
+
+impl Display for Report {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let header = ["Input", "Part", "Result", "Answer", "Expected", "Time"];
+        let rows: Vec<[String; 6]> = self
+            .checks
+            .iter()
+            .map(|c| {
+                [
+                    c.kind.to_string(),
+                    c.part.to_string(),
+                    c.verdict.to_string(),
+                    match &c.answer {
+                        Ok(answer) => cell(Some(answer)),
+                        Err(_) => "error".to_string(),
+                    },
+                    cell(c.expected.as_deref()),
+                    format!("{:.2?}", c.elapsed),
+                ]
+            })
+            .collect();
+
+        let mut widths = header.map(|h| h.len());
+        for row in &rows {
+            for (width, value) in widths.iter_mut().zip(row) {
+                *width = (*width).max(value.chars().count());
+            }
+        }
+
+        writeln!(f, "AOC {} day {}", self.year, self.day)?;
+        let header = header.map(|h| h.to_string());
+        for row in std::iter::once(&header).chain(&rows) {
+            let line: Vec<String> = row
+                .iter()
+                .zip(widths)
+                .map(|(value, width)| format!("{:<width$}", value, width = width))
+                .collect();
+            writeln!(f, "  {}", line.join(" ").trim_end())?;
+        }
+
+        if let Some(error) = &self.answers_error {
+            writeln!(f, "  Error reading {}:", ANSWERS_FILE)?;
+            for line in error.lines() {
+                writeln!(f, "    {}", line)?;
+            }
+        }
+        for check in &self.checks {
+            if let Err(error) = &check.answer {
+                writeln!(f, "  Error {} part {}:", check.kind, check.part)?;
+                for line in error.lines() {
+                    writeln!(f, "    {}", line)?;
+                }
+            }
+            let mut pictures = vec![("Answer", check.answer.as_ref().ok())];
+            if check.verdict == Verdict::Fail {
+                pictures.push(("Expected", check.expected.as_ref()));
+            }
+            for (label, text) in pictures {
+                if let Some(text) = text.filter(|t| t.contains('\n')) {
+                    writeln!(f, "  {} {} part {}:", label, check.kind, check.part)?;
+                    for line in text.lines() {
+                        writeln!(f, "    {}", line)?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Solves the chosen parts against the chosen inputs and compares each
+/// answer with the day's answers file. Inputs that don't exist are skipped.
+pub fn verify(solution: &dyn Solution, kinds: &[InputKind], parts: &[Part]) -> Report {
+    let (answers, answers_error) = match Answers::load(solution.directory()) {
+        Ok(answers) => (answers, None),
+        Err(e) => (Answers::default(), Some(e)),
+    };
+
+    let mut checks = Vec::new();
+    for kind in kinds {
+        if !Path::new(solution.directory())
+            .join(kind.file_name())
+            .exists()
+        {
+            continue;
+        }
+        let input = solution.load_input(*kind);
+        for part in parts {
+            let start = Instant::now();
+            let answer = match &input {
+                Ok(input) => {
+                    panic::catch_unwind(AssertUnwindSafe(|| solution.solve(*part, input, *kind)))
+                        .map_err(|_| "panicked".to_string())
+                        .and_then(|answer| answer.map_err(|e| e.to_string()))
+                }
+                Err(e) => Err(e.to_string()),
+            };
+            let elapsed = start.elapsed();
+
+            let verdict = match &answer {
+                Ok(answer) => answers.verdict(*kind, *part, answer),
+                Err(_) => Verdict::Fail,
+            };
+            checks.push(Check {
+                kind: *kind,
+                part: *part,
+                answer,
+                expected: answers.expected(*kind, *part).map(|s| s.to_string()),
+                elapsed,
+                verdict,
+            });
+        }
+    }
+
+    Report {
+        year: solution.year(),
+        day: solution.day(),
+        checks,
+        answers_error,
+    }
+}
+
+/// The entry point of a day's binary: checks both parts against both inputs
+/// and prints the summary table.
+pub fn check(solution: &dyn Solution) -> ExitCode {
+    let report = verify(solution, &EACH_INPUT_KIND, &EACH_PART);
+    print!("{}", report);
+    report.exit_code()
+}
+
+/// Asserts that a part solves an input to the answer recorded in the day's
+/// answers file. The puzzle input is optional, and is only checked when both
+/// it and its answer are there; a missing sample or sample answer fails the
+/// assertion, so that a day isn't passed without being checked.
+pub fn assert_answer(solution: &dyn Solution, kind: InputKind, part: Part) {
+    let answers = Answers::load(solution.directory()).unwrap_or_else(|e| panic!("{}", e));
+    let input_exists = Path::new(solution.directory())
+        .join(kind.file_name())
+        .exists();
+    let expected = answers.expected(kind, part);
+    if kind == InputKind::Puzzle && (!input_exists || expected.is_none()) {
+        return;
+    }
+    let Some(expected) = expected else {
+        panic!(
+            "no {} part {} answer in {}",
+            kind,
+            part,
+            Path::new(solution.directory()).join(ANSWERS_FILE).display()
+        );
+    };
+    let answer = solution
+        .load_input(kind)
+        .and_then(|input| solution.solve(part, &input, kind))
+        .unwrap_or_else(|e| panic!("{}", e));
+    assert_eq!(
+        answer,
+        expected,
+        "AOC {} day {} part {} ({})",
+        solution.year(),
+        solution.day(),
+        part,
+        kind
+    );
+}
+
+/// Generates a `#[test]` for both parts of the calling day crate's
+/// `SOLUTION` against each input, named after the input and part
+/// (`sample_tests::sample::part1`). The expected answers are read from the
+/// day's answers file, and the puzzle tests pass without checking anything
+/// until its `input.txt` and answers are there.
+///
+/// ```ignore
+/// // Both the sample and the puzzle input
+/// aoc::sample_tests!();
+///
+/// // Only the sample
+/// aoc::sample_tests!(sample);
+/// ```
+// `crate` is meant to name the calling day crate, which declares `SOLUTION`
+#[allow(clippy::crate_in_macro_def)]
+#[macro_export]
+macro_rules! sample_tests {
+    () => {
+        $crate::sample_tests!(sample, puzzle);
+    };
+    ($($kind:ident),+ $(,)?) => {
+        #[cfg(test)]
+        mod sample_tests {
+            $(
+                mod $kind {
+                    #[test]
+                    fn part1() {
+                        $crate::answers::assert_answer(
+                            &crate::SOLUTION,
+                            stringify!($kind).parse().unwrap(),
+                            $crate::solution::Part::One,
+                        );
+                    }
+
+                    #[test]
+                    fn part2() {
+                        $crate::answers::assert_answer(
+                            &crate::SOLUTION,
+                            stringify!($kind).parse().unwrap(),
+                            $crate::solution::Part::Two,
+                        );
+                    }
+                }
+            )+
+        }
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Answers, Report, Verdict};
+    use crate::solution::{InputKind, Part};
+
+    #[test]
+    fn parse() {
+        let answers = Answers::parse(
+            "[sample]\npart1 = \"CMZ\"\npart2 = 93\n\n[puzzle]\npart2 = '''\n#.\n.#'''\n",
+        )
+        .unwrap();
+        assert_eq!(answers.expected(InputKind::Sample, Part::One), Some("CMZ"));
+        assert_eq!(answers.expected(InputKind::Sample, Part::Two), Some("93"));
+        assert_eq!(answers.expected(InputKind::Puzzle, Part::One), None);
+        assert_eq!(
+            answers.expected(InputKind::Puzzle, Part::Two),
+            Some("#.\n.#")
+        );
+
+        assert!(Answers::parse("[real]\npart1 = 1").is_err());
+        assert!(Answers::parse("[sample]\npart3 = 1").is_err());
+        assert!(Answers::parse("[sample]\npart1 = 1.5").is_err());
+    }
+
+    #[test]
+    fn verdicts() {
+        let answers = Answers::parse("[sample]\npart1 = 24").unwrap();
+        assert_eq!(
+            answers.verdict(InputKind::Sample, Part::One, "24"),
+            Verdict::Pass
+        );
+        assert_eq!(
+            answers.verdict(InputKind::Sample, Part::One, "25"),
+            Verdict::Fail
+        );
+        assert_eq!(
+            answers.verdict(InputKind::Sample, Part::Two, "93"),
+            Verdict::Unknown
+        );
+    }
+
+    #[test]
+    fn unreadable_answers_fail_the_report() {
+        let report = Report {
+            year: 2022,
+            day: 1,
+            checks: Vec::new(),
+            answers_error: Some("answers.toml: expected `=`".to_string()),
+        };
+        assert!(!report.passed());
+        assert!(report
+            .to_string()
+            .contains("Error reading answers.toml:\n    answers.toml: expected `=`"));
+    }
+}
@@ -0,0 +1,317 @@
+//! Sets of a few named things, such as which valves are open, small enough to
+//! copy around, hash cheaply and use as keys.
+//!
+//! An [`Interner`] numbers the names and a [`SmallBitSet`] holds the numbers.
+
+use std::{borrow::Borrow, collections::HashMap, fmt::Debug, hash::Hash, ops::Index};
+
+/// Gives each distinct item a number, counting up from 0 in the order they
+/// were first seen.
+#[derive(Debug, Clone)]
+pub struct Interner<T> {
+    items: Vec<T>,
+    indices: HashMap<T, usize>,
+}
+
+impl<T> Default for Interner<T> {
+    fn default() -> Self {
+        Self {
+            items: Vec::new(),
+            indices: HashMap::new(),
+        }
+    }
+}
+
+impl<T> Interner<T>
+where
+    T: Hash + Eq + Clone,
+{
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The number of `item`, giving it the next one if it's new.
+    pub fn intern(&mut self, item: T) -> usize {
+        if let Some(&index) = self.indices.get(&item) {
+            return index;
+        }
+        let index = self.items.len();
+        self.items.push(item.clone());
+        self.indices.insert(item, index);
+        index
+    }
+
+    /// The number of `item`, if it has one.
+    pub fn index<Q>(&self, item: &Q) -> Option<usize>
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.indices.get(item).copied()
+    }
+
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.items.get(index)
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// The items in the order of their numbers.
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.items.iter()
+    }
+}
+
+impl<T> Index<usize> for Interner<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &T {
+        &self.items[index]
+    }
+}
+
+impl<T> FromIterator<T> for Interner<T>
+where
+    T: Hash + Eq + Clone,
+{
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut interner = Self::new();
+        for item in iter {
+            interner.intern(item);
+        }
+        interner
+    }
+}
+
+/// A set of numbers below `64 * WORDS`, one bit each.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SmallBitSet<const WORDS: usize = 1> {
+    words: [u64; WORDS],
+}
+
+impl<const WORDS: usize> Default for SmallBitSet<WORDS> {
+    fn default() -> Self {
+        Self { words: [0; WORDS] }
+    }
+}
+
+impl<const WORDS: usize> SmallBitSet<WORDS> {
+    /// One more than the largest number the set can hold.
+    pub const CAPACITY: usize = 64 * WORDS;
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn bit(index: usize) -> (usize, u64) {
+        assert!(
+            index < Self::CAPACITY,
+            "{} doesn't fit in a set of {} bits",
+            index,
+            Self::CAPACITY
+        );
+        (index / 64, 1 << (index % 64))
+    }
+
+    /// Adds `index`, returning whether it was new.
+    pub fn insert(&mut self, index: usize) -> bool {
+        let (word, bit) = Self::bit(index);
+        let new = self.words[word] & bit == 0;
+        self.words[word] |= bit;
+        new
+    }
+
+    /// Takes out `index`, returning whether it was there.
+    pub fn remove(&mut self, index: usize) -> bool {
+        let (word, bit) = Self::bit(index);
+        let present = self.words[word] & bit != 0;
+        self.words[word] &= !bit;
+        present
+    }
+
+    /// A copy of the set with `index` added.
+    pub fn with(mut self, index: usize) -> Self {
+        self.insert(index);
+        self
+    }
+
+    /// A copy of the set without `index`.
+    pub fn without(mut self, index: usize) -> Self {
+        self.remove(index);
+        self
+    }
+
+    pub fn contains(&self, index: usize) -> bool {
+        index < Self::CAPACITY && {
+            let (word, bit) = Self::bit(index);
+            self.words[word] & bit != 0
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.iter().all(|&w| w == 0)
+    }
+
+    fn zip(self, other: Self, f: impl Fn(u64, u64) -> u64) -> Self {
+        let mut words = self.words;
+        for (word, other) in words.iter_mut().zip(other.words) {
+            *word = f(*word, other);
+        }
+        Self { words }
+    }
+
+    pub fn union(self, other: Self) -> Self {
+        self.zip(other, |a, b| a | b)
+    }
+
+    pub fn intersection(self, other: Self) -> Self {
+        self.zip(other, |a, b| a & b)
+    }
+
+    /// The members of `self` that aren't in `other`.
+    pub fn difference(self, other: Self) -> Self {
+        self.zip(other, |a, b| a & !b)
+    }
+
+    pub fn is_subset(&self, other: &Self) -> bool {
+        self.difference(*other).is_empty()
+    }
+
+    pub fn is_superset(&self, other: &Self) -> bool {
+        other.is_subset(self)
+    }
+
+    pub fn is_disjoint(&self, other: &Self) -> bool {
+        self.intersection(*other).is_empty()
+    }
+
+    /// The members in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        self.words.iter().enumerate().flat_map(|(i, &word)| {
+            let mut rest = word;
+            std::iter::from_fn(move || {
+                if rest == 0 {
+                    return None;
+                }
+                let bit = rest.trailing_zeros() as usize;
+                rest &= rest - 1;
+                Some(i * 64 + bit)
+            })
+        })
+    }
+
+    /// Every subset of the set, from the empty set to the whole of it. Each
+    /// subset comes after all of its own subsets, so results for the smaller
+    /// sets are ready when building on them.
+    pub fn subsets(self) -> impl Iterator<Item = Self> {
+        let members: Vec<usize> = self.iter().collect();
+        assert!(
+            members.len() < 64,
+            "too many subsets of {} members to list",
+            members.len()
+        );
+        (0..1u64 << members.len()).map(move |count| {
+            let mut subset = Self::new();
+            for (i, &member) in members.iter().enumerate() {
+                if count & (1 << i) != 0 {
+                    subset.insert(member);
+                }
+            }
+            subset
+        })
+    }
+
+    /// Every way to split the set into two that don't overlap, as each subset
+    /// and the rest. Both orders of each split are included, as is the split
+    /// into the empty set and the whole.
+    pub fn splits(self) -> impl Iterator<Item = (Self, Self)> {
+        self.subsets()
+            .map(move |part| (part, self.difference(part)))
+    }
+}
+
+impl<const WORDS: usize> FromIterator<usize> for SmallBitSet<WORDS> {
+    fn from_iter<I: IntoIterator<Item = usize>>(iter: I) -> Self {
+        let mut set = Self::new();
+        for index in iter {
+            set.insert(index);
+        }
+        set
+    }
+}
+
+impl<const WORDS: usize> Debug for SmallBitSet<WORDS> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashSet;
+
+    use super::{Interner, SmallBitSet};
+
+    #[test]
+    fn interned_names() {
+        let mut names: Interner<String> = ["AA", "BB"].iter().map(|s| s.to_string()).collect();
+        assert_eq!(names.intern("CC".to_string()), 2);
+        assert_eq!(names.intern("AA".to_string()), 0);
+        assert_eq!(names.index("BB"), Some(1));
+        assert_eq!(names.index("DD"), None);
+        assert_eq!(names[2], "CC");
+        assert_eq!(names.len(), 3);
+    }
+
+    #[test]
+    fn set_operations() {
+        let a: SmallBitSet<2> = [1, 5, 64, 100].into_iter().collect();
+        let b: SmallBitSet<2> = [5, 100].into_iter().collect();
+        assert_eq!(a.len(), 4);
+        assert!(a.contains(64) && !a.contains(63) && !a.contains(500));
+        assert!(b.is_subset(&a) && a.is_superset(&b) && !a.is_subset(&b));
+        assert_eq!(a.difference(b).iter().collect::<Vec<_>>(), [1, 64]);
+        assert!(a.difference(b).is_disjoint(&b));
+        assert_eq!(a.difference(b).union(b), a);
+        assert_eq!(a.intersection(b), b);
+        assert_eq!(b.with(7).without(100).iter().collect::<Vec<_>>(), [5, 7]);
+        assert_eq!(format!("{:?}", b), "{5, 100}");
+
+        let mut c = SmallBitSet::<1>::new();
+        assert!(c.insert(63) && !c.insert(63));
+        assert!(c.remove(63) && !c.remove(63));
+        assert!(c.is_empty());
+    }
+
+    #[test]
+    fn subsets_and_splits() {
+        let set: SmallBitSet = [2, 3, 9].into_iter().collect();
+        let subsets: Vec<_> = set.subsets().collect();
+        assert_eq!(subsets.len(), 8);
+        assert_eq!(subsets.iter().collect::<HashSet<_>>().len(), 8);
+        for (i, subset) in subsets.iter().enumerate() {
+            assert!(subset.is_subset(&set));
+            // Smaller subsets come first
+            for member in subset.iter() {
+                let smaller = subset.without(member);
+                assert!(subsets[..i].contains(&smaller));
+            }
+        }
+
+        for (a, b) in set.splits() {
+            assert!(a.is_disjoint(&b));
+            assert_eq!(a.union(b), set);
+        }
+        assert_eq!(set.splits().count(), 8);
+    }
+}
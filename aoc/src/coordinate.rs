@@ -4,7 +4,7 @@ use std::{
     ops::{Add, Deref, DerefMut, Sub},
 };
 
-use crate::position::Direction;
+use crate::position::{Direction, Direction8, EACH_DIRECTION, EACH_DIRECTION8};
 use crate::range::Range;
 
 pub enum HorizontalAxisOrientation {
@@ -72,6 +72,49 @@ where
         self.down(1.into())
     }
 
+    /// The four positions directly up, down, left and right of this one.
+    fn neighbors4(&self) -> [Self; 4]
+    where
+        Self::Unit: From<u8>,
+    {
+        EACH_DIRECTION.map(|d| self.project(d, 1.into()))
+    }
+
+    /// The eight positions surrounding this one, including diagonals,
+    /// clockwise from the one above.
+    fn neighbors8(&self) -> [Self; 8]
+    where
+        Self::Unit: From<u8>,
+    {
+        EACH_DIRECTION8.map(|d| self.project8(d, 1.into()))
+    }
+
+    /// The [`Coordinate::neighbors4`] that lie inside `range`.
+    fn neighbors_within<'a>(
+        &self,
+        range: &'a RectangularRange<Self>,
+    ) -> impl Iterator<Item = Self> + 'a
+    where
+        Self::Unit: From<u8>,
+    {
+        self.neighbors4()
+            .into_iter()
+            .filter(move |n| range.contains(n))
+    }
+
+    /// The [`Coordinate::neighbors8`] that lie inside `range`.
+    fn neighbors8_within<'a>(
+        &self,
+        range: &'a RectangularRange<Self>,
+    ) -> impl Iterator<Item = Self> + 'a
+    where
+        Self::Unit: From<u8>,
+    {
+        self.neighbors8()
+            .into_iter()
+            .filter(move |n| range.contains(n))
+    }
+
     fn horizontal_relative_to(&self, other: &Self) -> (Self::Unit, Direction) {
         match Self::HORIZONTAL_AXIS_ORIENTATION {
             HorizontalAxisOrientation::PositiveRight => {
@@ -118,6 +161,13 @@ where
         }
     }
 
+    /// The direction (possibly diagonal) to step in to get closer to `other`.
+    fn direction8_towards(&self, other: &Self) -> Direction8 {
+        let (_, horizontal) = self.horizontal_relative_to(other);
+        let (_, vertical) = self.vertical_relative_to(other);
+        Direction8::from_components(vertical.opposite(), horizontal.opposite())
+    }
+
     fn manhatten_distance(&self, other: &Self) -> Self::Unit {
         let (h, _hdir) = self.horizontal_relative_to(other);
         let (v, _vdir) = self.vertical_relative_to(other);
@@ -158,6 +208,13 @@ where
             Direction::None => self.clone(),
         }
     }
+
+    /// Moves `distance` along both axes of a diagonal `direction`.
+    fn project8(&self, direction: Direction8, distance: Self::Unit) -> Self {
+        let (vertical, horizontal) = direction.components();
+        self.project(vertical, distance)
+            .project(horizontal, distance)
+    }
 }
 
 pub trait RectangularRangeBounds<C: Coordinate> {
@@ -173,6 +230,7 @@ where
     }
 }
 
+#[derive(Clone)]
 pub struct RectangularRange<C>
 where
     C: Coordinate,
@@ -369,6 +427,7 @@ impl<C: Coordinate> RectangularRange<C> {
     }
 }
 
+#[derive(Clone)]
 pub struct HorizontalRange<C>
 where
     C: Coordinate,
@@ -431,6 +490,7 @@ where
     }
 }
 
+#[derive(Clone)]
 pub struct VerticalRange<C>
 where
     C: Coordinate,
@@ -619,3 +679,53 @@ impl<
         &self.row
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::{Coordinate, RectangularRange, RowCol, XY};
+    use crate::position::Direction8;
+
+    #[test]
+    fn neighbors_follow_axis_orientation() {
+        let up = XY::<i32>::new(0, 0);
+        assert_eq!(up.neighbors8()[0], XY::new(0, 1));
+        assert_eq!(up.neighbors8()[1], XY::new(1, 1));
+
+        let down = RowCol::<i32>::new(0, 0);
+        assert_eq!(down.neighbors8()[0], RowCol::new(-1, 0));
+        assert_eq!(down.neighbors8()[1], RowCol::new(-1, 1));
+        assert_eq!(
+            down.neighbors4(),
+            [
+                RowCol::new(-1, 0),
+                RowCol::new(1, 0),
+                RowCol::new(0, -1),
+                RowCol::new(0, 1)
+            ]
+        );
+    }
+
+    #[test]
+    fn neighbors_within_clip_to_range() {
+        let range = RectangularRange::from_points(&[RowCol::new(0, 0), RowCol::new(2, 2)]);
+        assert_eq!(RowCol::new(0, 0).neighbors_within(&range).count(), 2);
+        assert_eq!(RowCol::new(0, 0).neighbors8_within(&range).count(), 3);
+        assert_eq!(RowCol::new(1, 1).neighbors8_within(&range).count(), 8);
+        assert_eq!(RowCol::new(0, 1).neighbors8_within(&range).count(), 5);
+    }
+
+    #[test]
+    fn direction8_towards() {
+        let c = RowCol::<i32>::new(5, 5);
+        assert_eq!(
+            c.direction8_towards(&RowCol::new(3, 6)),
+            Direction8::UpRight
+        );
+        assert_eq!(c.direction8_towards(&RowCol::new(5, 0)), Direction8::Left);
+        assert_eq!(c.direction8_towards(&c), Direction8::None);
+        assert_eq!(
+            c.project8(c.direction8_towards(&RowCol::new(9, 9)), 2),
+            RowCol::new(7, 7)
+        );
+    }
+}
@@ -0,0 +1,271 @@
+use std::{
+    fmt::Display,
+    iter::Step,
+    ops::{Add, Sub},
+};
+
+use crate::range::Range;
+
+#[derive(Debug, Default, Clone, Copy, Hash, PartialEq, Eq)]
+pub struct XYZ<U = i32> {
+    x: U,
+    y: U,
+    z: U,
+}
+
+impl<U> XYZ<U> {
+    pub fn new(x: U, y: U, z: U) -> Self {
+        Self { x, y, z }
+    }
+    pub fn x(&self) -> &U {
+        &self.x
+    }
+    pub fn y(&self) -> &U {
+        &self.y
+    }
+    pub fn z(&self) -> &U {
+        &self.z
+    }
+}
+
+impl<U: Display> Display for XYZ<U> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!("<x={},y={},z={}>", self.x, self.y, self.z))
+    }
+}
+
+impl<U> XYZ<U>
+where
+    U: Copy + PartialOrd + Add<U, Output = U> + Sub<U, Output = U>,
+{
+    pub fn manhatten_distance(&self, other: &Self) -> U {
+        distance(self.x, other.x) + distance(self.y, other.y) + distance(self.z, other.z)
+    }
+
+    /// The six positions sharing a face with this one.
+    pub fn neighbors6(&self) -> [Self; 6]
+    where
+        U: From<u8>,
+    {
+        [
+            self.offset(-1, 0, 0),
+            self.offset(1, 0, 0),
+            self.offset(0, -1, 0),
+            self.offset(0, 1, 0),
+            self.offset(0, 0, -1),
+            self.offset(0, 0, 1),
+        ]
+    }
+
+    /// The 26 positions sharing a face, edge or corner with this one.
+    pub fn neighbors26(&self) -> [Self; 26]
+    where
+        U: From<u8>,
+    {
+        let mut neighbors = [*self; 26];
+        let mut i = 0;
+        for dx in -1..=1 {
+            for dy in -1..=1 {
+                for dz in -1..=1 {
+                    if (dx, dy, dz) != (0, 0, 0) {
+                        neighbors[i] = self.offset(dx, dy, dz);
+                        i += 1;
+                    }
+                }
+            }
+        }
+        neighbors
+    }
+
+    fn offset(&self, dx: i8, dy: i8, dz: i8) -> Self
+    where
+        U: From<u8>,
+    {
+        Self::new(step(self.x, dx), step(self.y, dy), step(self.z, dz))
+    }
+}
+
+fn distance<U: Copy + PartialOrd + Sub<U, Output = U>>(a: U, b: U) -> U {
+    if a > b {
+        a - b
+    } else {
+        b - a
+    }
+}
+
+fn step<U: Add<U, Output = U> + Sub<U, Output = U> + From<u8>>(value: U, delta: i8) -> U {
+    if delta < 0 {
+        value - U::from(delta.unsigned_abs())
+    } else {
+        value + U::from(delta as u8)
+    }
+}
+
+/// An axis-aligned box of [`XYZ`] positions, the 3D counterpart of
+/// [`crate::coordinate::RectangularRange`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CuboidRange<U = i32> {
+    x: Range<U>,
+    y: Range<U>,
+    z: Range<U>,
+}
+
+impl<U> CuboidRange<U>
+where
+    U: Copy + PartialOrd,
+{
+    pub fn new(x: Range<U>, y: Range<U>, z: Range<U>) -> Self {
+        if x.is_empty() || y.is_empty() || z.is_empty() {
+            Self::empty()
+        } else {
+            Self { x, y, z }
+        }
+    }
+
+    pub fn empty() -> Self {
+        Self {
+            x: Range::empty(),
+            y: Range::empty(),
+            z: Range::empty(),
+        }
+    }
+
+    pub fn from_points(points: &[XYZ<U>]) -> Self {
+        points
+            .iter()
+            .fold(Self::empty(), |range, p| range.extended(p))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.x.is_empty()
+    }
+
+    pub fn x(&self) -> &Range<U> {
+        &self.x
+    }
+
+    pub fn y(&self) -> &Range<U> {
+        &self.y
+    }
+
+    pub fn z(&self) -> &Range<U> {
+        &self.z
+    }
+
+    /// The corner with the lowest value on every axis.
+    pub fn min(&self) -> Option<XYZ<U>> {
+        Some(XYZ::new(
+            *self.x.start()?,
+            *self.y.start()?,
+            *self.z.start()?,
+        ))
+    }
+
+    /// The corner with the highest value on every axis.
+    pub fn max(&self) -> Option<XYZ<U>> {
+        Some(XYZ::new(*self.x.end()?, *self.y.end()?, *self.z.end()?))
+    }
+
+    pub fn extended(&self, point: &XYZ<U>) -> Self {
+        Self {
+            x: self.x.extended(&point.x),
+            y: self.y.extended(&point.y),
+            z: self.z.extended(&point.z),
+        }
+    }
+
+    pub fn extend(&mut self, point: &XYZ<U>) {
+        *self = self.extended(point);
+    }
+
+    pub fn contains(&self, point: &XYZ<U>) -> bool {
+        self.x.contains(&point.x) && self.y.contains(&point.y) && self.z.contains(&point.z)
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        Self::new(
+            self.x.intersect(&other.x),
+            self.y.intersect(&other.y),
+            self.z.intersect(&other.z),
+        )
+    }
+
+    /// Grows the range by `distance` in every direction, such as to leave a
+    /// layer of empty space around a shape.
+    pub fn expanded(&self, distance: usize) -> Self
+    where
+        U: Step,
+    {
+        let expand = |r: &Range<U>| match (r.start(), r.end()) {
+            (Some(start), Some(end)) => {
+                Range::new(Step::backward(*start, distance)..=Step::forward(*end, distance))
+            }
+            _ => Range::empty(),
+        };
+        Self::new(expand(&self.x), expand(&self.y), expand(&self.z))
+    }
+
+    /// The number of positions in the range.
+    pub fn volume(&self) -> usize
+    where
+        U: Step,
+    {
+        self.x.count() * self.y.count() * self.z.count()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = XYZ<U>> + '_
+    where
+        U: Step + 'static,
+    {
+        self.x.iter().flat_map(move |x| {
+            self.y
+                .iter()
+                .flat_map(move |y| self.z.iter().map(move |z| XYZ::new(x, y, z)))
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{CuboidRange, XYZ};
+    use crate::range::Range;
+
+    #[test]
+    fn neighbors() {
+        let p = XYZ::new(1, 1, 1);
+        assert!(p.neighbors6().iter().all(|n| n.manhatten_distance(&p) == 1));
+        assert!(p.neighbors26().iter().all(|n| *n != p));
+        assert_eq!(
+            p.neighbors26()
+                .iter()
+                .filter(|n| n.manhatten_distance(&p) == 3)
+                .count(),
+            8
+        );
+        let unsigned = XYZ::<u32>::new(1, 1, 1);
+        assert!(unsigned.neighbors26().contains(&XYZ::new(0, 0, 0)));
+    }
+
+    #[test]
+    fn cuboid() {
+        let a = CuboidRange::from_points(&[XYZ::new(0, 0, 0), XYZ::new(3, 2, 1)]);
+        assert_eq!(a.volume(), 24);
+        assert_eq!(a.iter().count(), 24);
+        assert!(a.iter().all(|p| a.contains(&p)));
+        assert!(!a.contains(&XYZ::new(0, 3, 0)));
+        assert_eq!(a.expanded(1).volume(), 6 * 5 * 4);
+        assert_eq!(a.expanded(1).min(), Some(XYZ::new(-1, -1, -1)));
+
+        let b = CuboidRange::new(Range::new(2..=5), Range::new(1..=1), Range::new(0..=1));
+        let overlap = a.intersection(&b);
+        assert_eq!(overlap.min(), Some(XYZ::new(2, 1, 0)));
+        assert_eq!(overlap.max(), Some(XYZ::new(3, 1, 1)));
+        assert_eq!(overlap.volume(), 4);
+
+        assert_eq!(b.intersection(&a), overlap);
+
+        let apart = CuboidRange::from_points(&[XYZ::new(5, 5, 5)]);
+        assert!(a.intersection(&apart).is_empty());
+        assert_eq!(a.intersection(&apart).volume(), 0);
+    }
+}
@@ -0,0 +1,308 @@
+//! Finding where a simulation starts repeating itself, to skip ahead to a
+//! step far beyond what could be simulated.
+//!
+//! [`floyd`] and [`brent`] need nothing but the step function, and keep only
+//! a couple of states at once. A [`History`] is for simulations that run in
+//! a loop of their own: each step's state is recorded by a key that says
+//! what matters about it, along with a value such as a height, which can then
+//! be extrapolated.
+
+use std::{
+    collections::HashMap,
+    hash::Hash,
+    ops::{Add, Mul, Sub},
+};
+
+/// The steps at which a sequence of states repeats.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    /// The first step of the first repetition.
+    pub start: usize,
+    pub length: usize,
+}
+
+impl Cycle {
+    /// The first step with the same state as `step`.
+    pub fn equivalent(&self, step: usize) -> usize {
+        if step < self.start {
+            step
+        } else {
+            self.start + (step - self.start) % self.length
+        }
+    }
+}
+
+/// Floyd's tortoise and hare. `step` must always give the same state for the
+/// same state, and the states must repeat eventually.
+pub fn floyd<S: Clone + PartialEq>(start: S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    let mut tortoise = step(&start);
+    let mut hare = step(&tortoise);
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        let halfway = step(&hare);
+        hare = step(&halfway);
+    }
+
+    // The hare is now a whole number of cycles ahead
+    let mut cycle_start = 0;
+    tortoise = start;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        cycle_start += 1;
+    }
+
+    let mut length = 1;
+    hare = step(&tortoise);
+    while tortoise != hare {
+        hare = step(&hare);
+        length += 1;
+    }
+    Cycle {
+        start: cycle_start,
+        length,
+    }
+}
+
+/// Brent's algorithm, which finds the same as [`floyd`] with fewer steps.
+pub fn brent<S: Clone + PartialEq>(start: S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    // Find the length by moving the tortoise to the hare at each power of two
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = start.clone();
+    let mut hare = step(&start);
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = step(&hare);
+        length += 1;
+    }
+
+    // Then the start, with the hare a cycle ahead
+    let mut tortoise = start.clone();
+    let mut hare = start;
+    for _ in 0..length {
+        hare = step(&hare);
+    }
+    let mut cycle_start = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        cycle_start += 1;
+    }
+    Cycle {
+        start: cycle_start,
+        length,
+    }
+}
+
+/// The states of a simulation so far, by key, and a value for each step.
+///
+/// ```
+/// use aoc::cycle::History;
+///
+/// // A tower growing by 1, 2 and 3 in turn
+/// let mut history = History::new();
+/// let (mut turn, mut height) = (0, 0u64);
+/// let cycle = loop {
+///     if let Some(cycle) = history.record(turn, height) {
+///         break cycle;
+///     }
+///     height += turn as u64 + 1;
+///     turn = (turn + 1) % 3;
+/// };
+/// assert_eq!((cycle.start, cycle.length), (0, 3));
+/// assert_eq!(history.extrapolate(&cycle, 3_000_000_000_000), 6_000_000_000_000);
+/// ```
+#[derive(Debug, Clone)]
+pub struct History<K, V> {
+    seen: HashMap<K, usize>,
+    values: Vec<V>,
+}
+
+impl<K, V> Default for History<K, V> {
+    fn default() -> Self {
+        Self {
+            seen: HashMap::new(),
+            values: Vec::new(),
+        }
+    }
+}
+
+impl<K, V> History<K, V>
+where
+    K: Hash + Eq,
+    V: Copy,
+{
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records the state after as many steps as have been recorded before,
+    /// returning the cycle if its key has been seen already.
+    pub fn record(&mut self, key: K, value: V) -> Option<Cycle> {
+        let step = self.values.len();
+        self.values.push(value);
+        match self.seen.get(&key) {
+            Some(&start) => Some(Cycle {
+                start,
+                length: step - start,
+            }),
+            None => {
+                self.seen.insert(key, step);
+                None
+            }
+        }
+    }
+
+    /// How many steps have been recorded.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// The value recorded for `step`, if it has been.
+    pub fn value(&self, step: usize) -> Option<V> {
+        self.values.get(step).copied()
+    }
+
+    /// The value at `step`, assuming that each trip round `cycle` changes
+    /// the value by the same amount.
+    pub fn extrapolate(&self, cycle: &Cycle, step: usize) -> V
+    where
+        V: Add<Output = V> + Sub<Output = V> + Mul<Output = V> + TryFrom<usize>,
+    {
+        if let Some(value) = self.value(step) {
+            return value;
+        }
+        let first = self.values[cycle.start];
+        let per_cycle = self.values[cycle.start + cycle.length] - first;
+        let cycles = (step - cycle.start) / cycle.length;
+        let Ok(cycles) = V::try_from(cycles) else {
+            panic!("{} cycles don't fit in the value type", cycles);
+        };
+        self.values[cycle.equivalent(step)] + per_cycle * cycles
+    }
+}
+
+/// Runs `step` on `state` `steps` times, or as many as it takes to find a
+/// cycle in the keys of the states, and gives the value there would be at
+/// the end.
+pub fn simulate<S, K, V>(
+    mut state: S,
+    steps: usize,
+    mut step: impl FnMut(&mut S),
+    mut key: impl FnMut(&S) -> K,
+    mut value: impl FnMut(&S) -> V,
+) -> V
+where
+    K: Hash + Eq,
+    V: Copy + Add<Output = V> + Sub<Output = V> + Mul<Output = V> + TryFrom<usize>,
+{
+    let mut history = History::new();
+    for _ in 0..steps {
+        if let Some(cycle) = history.record(key(&state), value(&state)) {
+            return history.extrapolate(&cycle, steps);
+        }
+        step(&mut state);
+    }
+    value(&state)
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+
+    use super::{brent, floyd, simulate, Cycle};
+
+    fn next(x: &u32) -> u32 {
+        (x * x + 1) % 255
+    }
+
+    // Stepping until a state comes round again
+    fn brute_force(start: u32) -> Cycle {
+        let mut seen = HashMap::new();
+        let mut x = start;
+        for step in 0.. {
+            if let Some(&first) = seen.get(&x) {
+                return Cycle {
+                    start: first,
+                    length: step - first,
+                };
+            }
+            seen.insert(x, step);
+            x = next(&x);
+        }
+        unreachable!()
+    }
+
+    #[test]
+    fn floyd_and_brent_agree() {
+        for start in 0..255 {
+            let expected = brute_force(start);
+            assert_eq!(floyd(start, next), expected, "from {}", start);
+            assert_eq!(brent(start, next), expected, "from {}", start);
+        }
+
+        let cycle = brute_force(3);
+        assert_eq!(
+            cycle,
+            Cycle {
+                start: 2,
+                length: 6
+            }
+        );
+        assert_eq!(cycle.equivalent(1), 1);
+        assert_eq!(cycle.equivalent(8), 2);
+        assert_eq!(cycle.equivalent(1_000_000), 2 + (1_000_000 - 2) % 6);
+    }
+
+    // A column that rocks drop onto, each rock's height picked by a wind
+    // pattern that comes round every 7 rocks after the first few
+    #[derive(Clone)]
+    struct Tower {
+        rock: usize,
+        wind: usize,
+        height: i64,
+    }
+
+    fn drop_rock(tower: &mut Tower) {
+        const HEIGHTS: [i64; 5] = [1, 3, 3, 4, 2];
+        tower.height += HEIGHTS[tower.rock] + tower.wind as i64;
+        tower.rock = (tower.rock + 1) % HEIGHTS.len();
+        tower.wind = if tower.wind < 3 {
+            tower.wind + 1
+        } else {
+            3 + (tower.wind - 2) % 7
+        };
+    }
+
+    #[test]
+    fn extrapolation_matches_simulation() {
+        let start = Tower {
+            rock: 0,
+            wind: 0,
+            height: 0,
+        };
+        let key = |t: &Tower| (t.rock, t.wind);
+        let height = |t: &Tower| t.height;
+
+        let mut tower = start.clone();
+        for steps in 0..500 {
+            let found = simulate(start.clone(), steps, drop_rock, key, height);
+            assert_eq!(found, tower.height, "after {} steps", steps);
+            drop_rock(&mut tower);
+        }
+
+        // From step 3 the rocks and wind both come round every 35 rocks,
+        // adding 7 * 13 for the rocks and 5 * 42 for the wind
+        let far = simulate(start, 3 + 35 * 10_000_000_000, drop_rock, key, height);
+        assert_eq!(far, 10 + 301 * 10_000_000_000);
+    }
+}
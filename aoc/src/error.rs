@@ -0,0 +1,220 @@
+use std::{
+    fmt::Display,
+    path::{Path, PathBuf},
+};
+
+use crate::ocr::OcrError;
+
+/// Why a day couldn't be solved: its input was missing or not in the
+/// expected format, or the letters of its answer couldn't be read.
+#[derive(Debug)]
+pub enum Error {
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    Parse(ParseError),
+    Ocr(OcrError),
+}
+
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+impl Error {
+    pub fn io(path: &Path, source: std::io::Error) -> Self {
+        Error::Io {
+            path: path.to_path_buf(),
+            source,
+        }
+    }
+
+    /// Names the input file a parse error was found in.
+    pub fn in_file(self, file: &str) -> Self {
+        match self {
+            Error::Parse(e) => Error::Parse(e.in_file(file)),
+            e => e,
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Io { path, source } => write!(f, "{}: {}", path.display(), source),
+            Error::Parse(e) => e.fmt(f),
+            Error::Ocr(e) => e.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io { source, .. } => Some(source),
+            Error::Parse(e) => Some(e),
+            Error::Ocr(e) => Some(e),
+        }
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(e: ParseError) -> Self {
+        Error::Parse(e)
+    }
+}
+
+impl From<OcrError> for Error {
+    fn from(e: OcrError) -> Self {
+        Error::Ocr(e)
+    }
+}
+
+/// A line of input that couldn't be parsed, and where on it things went
+/// wrong.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub file: Option<String>,
+    /// Counted from 1, like an editor does.
+    pub line: usize,
+    /// Counted from 1, if the parser knows it.
+    pub column: Option<usize>,
+    pub text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn in_file(self, file: &str) -> Self {
+        Self {
+            file: Some(file.to_string()),
+            ..self
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}:{}",
+            self.file.as_deref().unwrap_or("input"),
+            self.line
+        )?;
+        if let Some(column) = self.column {
+            write!(f, ":{}", column)?;
+        }
+        writeln!(f, ": {}", self.message)?;
+        write!(f, "    {}", self.text)?;
+        if let Some(column) = self.column {
+            write!(f, "\n    {:>width$}", "^", width = column)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// The failure of a line parser passed to [`parse_lines`], which adds the
+/// line it happened on. Any displayable error converts into one, so `?` works
+/// on `str::parse`, `scan_fmt!` and the like.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LineError {
+    column: Option<usize>,
+    message: String,
+}
+
+impl LineError {
+    pub fn new(message: impl Display) -> Self {
+        Self {
+            column: None,
+            message: message.to_string(),
+        }
+    }
+
+    /// Points the error at a column of the line, counted from 1.
+    pub fn at(self, column: usize) -> Self {
+        Self {
+            column: Some(column),
+            ..self
+        }
+    }
+
+    pub fn column(&self) -> Option<usize> {
+        self.column
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl<E: Display> From<E> for LineError {
+    fn from(e: E) -> Self {
+        Self::new(e)
+    }
+}
+
+/// Parses each line of `input`, stopping at the first that fails.
+///
+/// ```
+/// use aoc::error::{parse_lines, LineError};
+///
+/// let moves = parse_lines("U 4\nX 2", |line| {
+///     let (direction, count) = line.split_once(' ').ok_or("expected a direction and count")?;
+///     if !"UDLR".contains(direction) {
+///         return Err(LineError::new("unknown direction").at(1));
+///     }
+///     Ok((direction.to_string(), count.parse::<i32>()?))
+/// });
+/// assert_eq!(moves.unwrap_err().line, 2);
+/// ```
+pub fn parse_lines<T>(
+    input: &str,
+    mut parse: impl FnMut(&str) -> Result<T, LineError>,
+) -> Result<Vec<T>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| {
+            parse(line).map_err(|e| ParseError {
+                file: None,
+                line: index + 1,
+                column: e.column,
+                text: line.to_string(),
+                message: e.message,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::{parse_lines, Error, LineError};
+
+    #[test]
+    fn errors_point_at_the_line() {
+        let numbers = parse_lines("1\n2\n", |line| Ok(line.parse::<u8>()?)).unwrap();
+        assert_eq!(numbers, [1, 2]);
+
+        let e = parse_lines("1\n2\n300\n", |line| Ok(line.parse::<u8>()?)).unwrap_err();
+        assert_eq!(e.line, 3);
+        assert_eq!(e.column, None);
+        assert_eq!(e.text, "300");
+        assert_eq!(
+            e.to_string(),
+            "input:3: number too large to fit in target type\n    300"
+        );
+
+        let e = parse_lines("R 4\nQ 1\n", |line| {
+            if line.starts_with('Q') {
+                Err(LineError::new("unknown direction 'Q'").at(1))
+            } else {
+                Ok(())
+            }
+        })
+        .unwrap_err();
+        let e = Error::from(e).in_file("input.txt");
+        assert_eq!(
+            e.to_string(),
+            "input.txt:2:1: unknown direction 'Q'\n    Q 1\n    ^"
+        );
+    }
+}
@@ -0,0 +1,350 @@
+//! Weighted graphs given as lists of edges, such as tunnel networks or the
+//! order steps depend on each other in.
+
+use std::{
+    borrow::Borrow,
+    cmp::Reverse,
+    collections::{BinaryHeap, HashSet},
+    hash::Hash,
+    ops::Add,
+};
+
+use crate::{bitset::Interner, search};
+
+/// A directed graph with weighted edges, kept as a list of the edges out of
+/// each node. An undirected graph has each edge both ways.
+#[derive(Debug, Clone)]
+pub struct Graph<N, W = usize> {
+    nodes: Interner<N>,
+    edges: Vec<Vec<(usize, W)>>,
+}
+
+impl<N, W> Default for Graph<N, W> {
+    fn default() -> Self {
+        Self {
+            nodes: Interner::default(),
+            edges: Vec::new(),
+        }
+    }
+}
+
+impl<N, W> Graph<N, W>
+where
+    N: Hash + Eq + Clone,
+    W: Copy + Ord + Default + Add<Output = W>,
+{
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Builds a graph with each of `edges` going both ways.
+    pub fn undirected(edges: impl IntoIterator<Item = (N, N, W)>) -> Self {
+        let mut graph = Self::new();
+        for (a, b, weight) in edges {
+            graph.add_edge(a.clone(), b.clone(), weight);
+            graph.add_edge(b, a, weight);
+        }
+        graph
+    }
+
+    /// Adds `node` without any edges, if it isn't in the graph already.
+    pub fn add_node(&mut self, node: N) -> usize {
+        let index = self.nodes.intern(node);
+        if index == self.edges.len() {
+            self.edges.push(Vec::new());
+        }
+        index
+    }
+
+    pub fn add_edge(&mut self, from: N, to: N, weight: W) {
+        let from = self.add_node(from);
+        let to = self.add_node(to);
+        self.edges[from].push((to, weight));
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// The nodes in the order they were added.
+    pub fn nodes(&self) -> impl Iterator<Item = &N> {
+        self.nodes.iter()
+    }
+
+    pub fn contains<Q>(&self, node: &Q) -> bool
+    where
+        N: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.nodes.index(node).is_some()
+    }
+
+    /// The edges out of `from`, as the node each leads to and its weight.
+    pub fn edges<Q>(&self, from: &Q) -> impl Iterator<Item = (&N, W)>
+    where
+        N: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let edges = match self.nodes.index(from) {
+            Some(from) => &self.edges[from][..],
+            None => &[],
+        };
+        edges.iter().map(|&(to, weight)| (&self.nodes[to], weight))
+    }
+
+    /// The weight of the lightest edge from `from` to `to`.
+    pub fn weight<Q>(&self, from: &Q, to: &Q) -> Option<W>
+    where
+        N: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.edges(from)
+            .filter(|(node, _)| (*node).borrow() == to)
+            .map(|(_, weight)| weight)
+            .min()
+    }
+
+    fn successors(&self, from: usize) -> impl Iterator<Item = (usize, W)> + '_ {
+        self.edges[from].iter().copied()
+    }
+
+    /// The distances between every pair of nodes by the Floyd-Warshall
+    /// algorithm, for small or densely connected graphs.
+    pub fn floyd_warshall(&self) -> Distances<N, W> {
+        let n = self.len();
+        let mut matrix = vec![vec![None; n]; n];
+        for (from, edges) in self.edges.iter().enumerate() {
+            matrix[from][from] = Some(W::default());
+            for &(to, weight) in edges {
+                let shortest = matrix[from][to].map_or(weight, |d: W| d.min(weight));
+                matrix[from][to] = Some(shortest);
+            }
+        }
+        for via in 0..n {
+            let via_row = matrix[via].clone();
+            for row in matrix.iter_mut() {
+                let Some(from_via) = row[via] else {
+                    continue;
+                };
+                for (shortest, via_to) in row.iter_mut().zip(&via_row) {
+                    if let Some(via_to) = via_to {
+                        let through = from_via + *via_to;
+                        if shortest.is_none_or(|d| through < d) {
+                            *shortest = Some(through);
+                        }
+                    }
+                }
+            }
+        }
+        Distances {
+            nodes: self.nodes.clone(),
+            matrix,
+        }
+    }
+
+    /// The distances between every pair of nodes by Dijkstra's algorithm from
+    /// each node in turn, for large sparse graphs.
+    pub fn dijkstra_all_pairs(&self) -> Distances<N, W> {
+        let matrix = (0..self.len())
+            .map(|from| self.dijkstra_row(from))
+            .collect();
+        Distances {
+            nodes: self.nodes.clone(),
+            matrix,
+        }
+    }
+
+    fn dijkstra_row(&self, from: usize) -> Vec<Option<W>> {
+        let result = search::dijkstra(from, |&node| self.successors(node));
+        (0..self.len())
+            .map(|to| result.distance(&to).copied())
+            .collect()
+    }
+
+    /// A graph of only the nodes `keep` accepts, with an edge between each
+    /// pair of them weighted by the shortest distance between them in this
+    /// graph. Paths may pass through the nodes left out.
+    pub fn contract(&self, mut keep: impl FnMut(&N) -> bool) -> Self {
+        let kept: Vec<usize> = (0..self.len()).filter(|&i| keep(&self.nodes[i])).collect();
+        let mut graph = Self::new();
+        for &from in &kept {
+            graph.add_node(self.nodes[from].clone());
+        }
+        for &from in &kept {
+            let distances = self.dijkstra_row(from);
+            for &to in kept.iter().filter(|&&to| to != from) {
+                if let Some(distance) = distances[to] {
+                    graph.add_edge(self.nodes[from].clone(), self.nodes[to].clone(), distance);
+                }
+            }
+        }
+        graph
+    }
+
+    /// The groups of nodes joined by edges, whichever way the edges go. Each
+    /// group lists its nodes in the order they were added.
+    pub fn connected_components(&self) -> Vec<Vec<N>> {
+        let mut neighbors = vec![Vec::new(); self.len()];
+        for (from, edges) in self.edges.iter().enumerate() {
+            for &(to, _) in edges {
+                neighbors[from].push(to);
+                neighbors[to].push(from);
+            }
+        }
+
+        let mut seen = HashSet::new();
+        let mut components = Vec::new();
+        for start in 0..self.len() {
+            if seen.contains(&start) {
+                continue;
+            }
+            let result = search::bfs(start, |&node| neighbors[node].clone());
+            let mut component: Vec<usize> = result.distances().keys().copied().collect();
+            component.sort();
+            seen.extend(component.iter().copied());
+            components.push(
+                component
+                    .into_iter()
+                    .map(|i| self.nodes[i].clone())
+                    .collect(),
+            );
+        }
+        components
+    }
+
+    /// The nodes ordered so that every edge goes forwards, or `None` if the
+    /// edges go round in a cycle. When several nodes could come next, the one
+    /// added first does.
+    pub fn topological_sort(&self) -> Option<Vec<N>> {
+        let mut incoming = vec![0; self.len()];
+        for edges in &self.edges {
+            for &(to, _) in edges {
+                incoming[to] += 1;
+            }
+        }
+
+        let mut ready: BinaryHeap<Reverse<usize>> = (0..self.len())
+            .filter(|&i| incoming[i] == 0)
+            .map(Reverse)
+            .collect();
+        let mut order = Vec::new();
+        while let Some(Reverse(node)) = ready.pop() {
+            order.push(self.nodes[node].clone());
+            for &(to, _) in &self.edges[node] {
+                incoming[to] -= 1;
+                if incoming[to] == 0 {
+                    ready.push(Reverse(to));
+                }
+            }
+        }
+        (order.len() == self.len()).then_some(order)
+    }
+}
+
+impl<N, W> FromIterator<(N, N, W)> for Graph<N, W>
+where
+    N: Hash + Eq + Clone,
+    W: Copy + Ord + Default + Add<Output = W>,
+{
+    /// A directed graph of the edges `(from, to, weight)`.
+    fn from_iter<I: IntoIterator<Item = (N, N, W)>>(edges: I) -> Self {
+        let mut graph = Self::new();
+        for (from, to, weight) in edges {
+            graph.add_edge(from, to, weight);
+        }
+        graph
+    }
+}
+
+/// The shortest distance from each node of a graph to each other.
+#[derive(Debug, Clone)]
+pub struct Distances<N, W> {
+    nodes: Interner<N>,
+    matrix: Vec<Vec<Option<W>>>,
+}
+
+impl<N, W> Distances<N, W>
+where
+    N: Hash + Eq + Clone,
+    W: Copy,
+{
+    /// The distance from `from` to `to`, if there is a path.
+    pub fn get<Q>(&self, from: &Q, to: &Q) -> Option<W>
+    where
+        N: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let from = self.nodes.index(from)?;
+        let to = self.nodes.index(to)?;
+        self.matrix[from][to]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Graph;
+
+    // a - b - c   d -> e
+    //  \_____/
+    fn sample() -> Graph<char, u32> {
+        let mut graph = Graph::undirected([('a', 'b', 2), ('b', 'c', 3), ('a', 'c', 9)]);
+        graph.add_edge('d', 'e', 4);
+        graph
+    }
+
+    #[test]
+    fn all_pairs_distances() {
+        let graph = sample();
+        for distances in [graph.floyd_warshall(), graph.dijkstra_all_pairs()] {
+            assert_eq!(distances.get(&'a', &'c'), Some(5));
+            assert_eq!(distances.get(&'c', &'a'), Some(5));
+            assert_eq!(distances.get(&'b', &'b'), Some(0));
+            assert_eq!(distances.get(&'d', &'e'), Some(4));
+            assert_eq!(distances.get(&'e', &'d'), None);
+            assert_eq!(distances.get(&'a', &'d'), None);
+            assert_eq!(distances.get(&'a', &'z'), None);
+        }
+    }
+
+    #[test]
+    fn contracted_to_important_nodes() {
+        let graph = sample();
+        let contracted = graph.contract(|&n| n != 'b');
+        assert_eq!(contracted.len(), 4);
+        assert!(!contracted.contains(&'b'));
+        assert_eq!(contracted.weight(&'a', &'c'), Some(5));
+        assert_eq!(contracted.weight(&'d', &'e'), Some(4));
+        assert_eq!(contracted.weight(&'a', &'d'), None);
+        assert_eq!(contracted.edges(&'a').count(), 1);
+    }
+
+    #[test]
+    fn components_and_order() {
+        let graph = sample();
+        assert_eq!(
+            graph.connected_components(),
+            [vec!['a', 'b', 'c'], vec!['d', 'e']]
+        );
+        assert_eq!(graph.topological_sort(), None);
+
+        // Steps that must be done before others, taking the first added when
+        // there's a choice
+        let steps: Graph<char, u32> = [
+            ('C', 'A', 1),
+            ('C', 'F', 1),
+            ('A', 'B', 1),
+            ('A', 'D', 1),
+            ('B', 'E', 1),
+            ('D', 'E', 1),
+            ('F', 'E', 1),
+        ]
+        .into_iter()
+        .collect();
+        let order: String = steps.topological_sort().unwrap().into_iter().collect();
+        assert_eq!(order, "CAFBDE");
+    }
+}
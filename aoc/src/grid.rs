@@ -1,7 +1,17 @@
-use std::{collections::HashMap, hash::Hash, iter::Step};
+use std::{
+    collections::{HashMap, HashSet},
+    convert::Infallible,
+    fmt::Display,
+    hash::Hash,
+    iter::Step,
+};
 
 use crate::{
-    coordinate::{Coordinate, RectangularRange, RowCol, XY},
+    coordinate::{
+        Coordinate, HorizontalAxisOrientation, HorizontalRange, RectangularRange, RowCol,
+        VerticalAxisOrientation, VerticalRange, XY,
+    },
+    range::Range,
     GetOrDefault,
 };
 
@@ -14,6 +24,16 @@ where
     range: RectangularRange<C>,
 }
 
+impl<T, C> Default for Grid<T, C>
+where
+    C: Coordinate + Hash + Eq + Clone,
+    C::Unit: Step,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<T, C> Grid<T, C>
 where
     C: Coordinate + Hash + Eq + Clone,
@@ -33,6 +53,31 @@ where
         }
     }
 
+    /// Builds a grid from puzzle text, with one tile per character. The first
+    /// line is the top row and the first character of each line is the left
+    /// column, whichever way the axes of `C` point.
+    pub fn parse<F: FnMut(C, char) -> T>(input: &str, tile: F) -> Self {
+        parse_tiles(input, tile)
+    }
+
+    /// Like [`Grid::parse`], but stops at the first character `tile` rejects.
+    pub fn try_parse<E, F: FnMut(C, char) -> Result<T, E>>(
+        input: &str,
+        tile: F,
+    ) -> Result<Self, E> {
+        try_parse_tiles(input, tile)
+    }
+
+    /// Like [`Grid::parse`], but also returns the positions at which each of
+    /// the `markers` characters appeared (such as a start and end point).
+    pub fn parse_with_markers<F: FnMut(C, char) -> T>(
+        input: &str,
+        markers: &[char],
+        tile: F,
+    ) -> (Self, HashMap<char, Vec<C>>) {
+        parse_tiles_with_markers(input, markers, tile)
+    }
+
     pub fn range(&self) -> &RectangularRange<C> {
         &self.range
     }
@@ -49,17 +94,6 @@ where
         self.tiles.values_mut()
     }
 
-    pub fn print<F: Fn(C, Option<&T>) -> String>(&self, tile_printer: F) {
-        for v in self.range.vertical().iter_top_top_bottom() {
-            for h in self.range.horizontal().iter_left_to_right() {
-                let c = C::from_horz_vert(h, v);
-                let t = self.get(&c);
-                print!("{}", tile_printer(c, t));
-            }
-            println!("");
-        }
-    }
-
     pub fn enumerate_tiles(&self) -> impl Iterator<Item = (C, Option<&T>)> {
         self.range.iter().map(|c| {
             let t = self.get(&c);
@@ -71,9 +105,7 @@ where
         T: Default,
     {
         for c in self.range.iter() {
-            if !self.tiles.contains_key(&c) {
-                self.tiles.insert(c, T::default());
-            }
+            self.tiles.entry(c).or_default();
         }
         self.tiles.iter_mut()
     }
@@ -103,16 +135,757 @@ where
 impl<T> From<Vec<Vec<T>>> for Grid<T, RowCol> {
     fn from(value: Vec<Vec<T>>) -> Self {
         let mut grid = Self::new();
-        let mut r = 0;
-        for row in value {
-            let mut c = 0;
-            for value in row {
-                let coordinate = RowCol::new(r as i32, c as i32);
-                grid.insert(coordinate, value);
-                c += 1;
+        for (r, row) in value.into_iter().enumerate() {
+            for (c, value) in row.into_iter().enumerate() {
+                grid.insert(RowCol::new(r as i32, c as i32), value);
+            }
+        }
+        grid
+    }
+}
+
+/// A grid backed by a row-major `Vec`, for puzzles where most of the range is
+/// populated and hashing every lookup in [`Grid`] dominates the runtime.
+///
+/// Tiles are stored relative to the top left corner of an allocated area that
+/// grows (with some slack) whenever `insert` or `get_mut_or_default` reaches
+/// outside of it. The logical `range` only ever covers the tiles that were
+/// touched, exactly like [`Grid`].
+pub struct DenseGrid<T, C = XY>
+where
+    C: Coordinate,
+    C::Unit: Step,
+{
+    tiles: Vec<Option<T>>,
+    allocated: RectangularRange<C>,
+    range: RectangularRange<C>,
+}
+
+impl<T, C> Default for DenseGrid<T, C>
+where
+    C: Coordinate,
+    C::Unit: Step,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, C> DenseGrid<T, C>
+where
+    C: Coordinate,
+    C::Unit: Step,
+{
+    pub fn new() -> Self {
+        Self {
+            tiles: Vec::new(),
+            allocated: RectangularRange::empty(),
+            range: RectangularRange::empty(),
+        }
+    }
+
+    /// Creates a grid with storage for every tile in `range` allocated up front.
+    pub fn new_from_range(range: RectangularRange<C>) -> Self {
+        let mut tiles = Vec::new();
+        tiles.resize_with(area(&range), || None);
+        Self {
+            tiles,
+            allocated: range.clone(),
+            range,
+        }
+    }
+
+    /// Like [`Grid::parse`], for a dense grid.
+    pub fn parse<F: FnMut(C, char) -> T>(input: &str, tile: F) -> Self {
+        parse_tiles(input, tile)
+    }
+
+    /// Like [`Grid::try_parse`], for a dense grid.
+    pub fn try_parse<E, F: FnMut(C, char) -> Result<T, E>>(
+        input: &str,
+        tile: F,
+    ) -> Result<Self, E> {
+        try_parse_tiles(input, tile)
+    }
+
+    /// Like [`Grid::parse_with_markers`], for a dense grid.
+    pub fn parse_with_markers<F: FnMut(C, char) -> T>(
+        input: &str,
+        markers: &[char],
+        tile: F,
+    ) -> (Self, HashMap<char, Vec<C>>) {
+        parse_tiles_with_markers(input, markers, tile)
+    }
+
+    pub fn range(&self) -> &RectangularRange<C> {
+        &self.range
+    }
+
+    pub fn range_mut(&mut self) -> &mut RectangularRange<C> {
+        &mut self.range
+    }
+
+    pub fn tiles(&self) -> impl Iterator<Item = &T> {
+        self.tiles.iter().flatten()
+    }
+
+    pub fn tiles_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.tiles.iter_mut().flatten()
+    }
+
+    pub fn enumerate_tiles(&self) -> impl Iterator<Item = (C, Option<&T>)> {
+        self.range.iter().map(|c| {
+            let t = self.get(&c);
+            (c, t)
+        })
+    }
+
+    pub fn enumerate_tiles_mut(&mut self) -> impl Iterator<Item = (C, &mut T)>
+    where
+        T: Default,
+    {
+        if let (Some(top_left), Some(bottom_right)) =
+            (self.range.top_left(), self.range.bottom_right())
+        {
+            self.reserve(&top_left);
+            self.reserve(&bottom_right);
+        }
+        for c in self.range.iter() {
+            let offset = self.offset(&c).unwrap();
+            self.tiles[offset].get_or_insert_with(T::default);
+        }
+        reading_order(&self.allocated)
+            .zip(self.tiles.iter_mut())
+            .filter_map(|(c, t)| t.as_mut().map(|t| (c, t)))
+    }
+
+    pub fn get(&self, position: &C) -> Option<&T> {
+        self.offset(position)
+            .and_then(|offset| self.tiles[offset].as_ref())
+    }
+
+    pub fn get_mut(&mut self, position: &C) -> Option<&mut T> {
+        self.offset(position)
+            .and_then(|offset| self.tiles[offset].as_mut())
+    }
+
+    pub fn get_mut_or_default(&mut self, position: &C) -> &mut T
+    where
+        T: Default,
+    {
+        self.range.extend(position);
+        self.reserve(position);
+        let offset = self.offset(position).unwrap();
+        self.tiles[offset].get_or_insert_with(T::default)
+    }
+
+    pub fn insert(&mut self, position: C, tile: T) -> Option<T> {
+        self.range.extend(&position);
+        self.reserve(&position);
+        let offset = self.offset(&position).unwrap();
+        self.tiles[offset].replace(tile)
+    }
+
+    /// Index of `position` in `tiles`, counting rows down from the top and
+    /// columns right from the left of the allocated area.
+    fn offset(&self, position: &C) -> Option<usize> {
+        if !self.allocated.contains(position) {
+            return None;
+        }
+        let width = self.allocated.horizontal().count();
+        let row = steps(self.allocated.top().unwrap(), position.vertical());
+        let col = steps(self.allocated.left().unwrap(), position.horizontal());
+        Some(row * width + col)
+    }
+
+    /// Grows the allocated area so that it covers `position`, moving the
+    /// existing tiles into the new layout.
+    fn reserve(&mut self, position: &C) {
+        if self.allocated.contains(position) {
+            return;
+        }
+
+        let allocated = if self.allocated.is_empty() {
+            self.allocated.extended(position)
+        } else {
+            // Pad each axis that had to grow by its current size so that a
+            // grid growing one row or column at a time is not re-laid out on
+            // every insert
+            RectangularRange::new(
+                HorizontalRange::new(padded(self.allocated.horizontal(), position.horizontal())),
+                VerticalRange::new(padded(self.allocated.vertical(), position.vertical())),
+            )
+        };
+
+        let mut tiles = Vec::new();
+        tiles.resize_with(area(&allocated), || None);
+
+        let old_tiles = std::mem::take(&mut self.tiles);
+        let old_allocated = std::mem::replace(&mut self.allocated, allocated);
+        for (c, t) in reading_order(&old_allocated).zip(old_tiles) {
+            if t.is_some() {
+                let offset = self.offset(&c).unwrap();
+                tiles[offset] = t;
+            }
+        }
+        self.tiles = tiles;
+    }
+}
+
+/// What [`Grid`] and [`DenseGrid`] have in common, and what is built on it
+/// for both.
+pub trait Tiles<T, C>
+where
+    C: Coordinate,
+    C::Unit: Step,
+{
+    fn tile(&self, position: &C) -> Option<&T>;
+    fn tile_range(&self) -> &RectangularRange<C>;
+
+    /// The positions up, down, left and right of `position` whose tiles can be
+    /// stepped onto from the tile at `position`, according to
+    /// `passable(from, to)`. Suitable as the successor function of a search in
+    /// [`crate::search`].
+    fn passable_neighbors<'a, F>(
+        &'a self,
+        position: &C,
+        passable: F,
+    ) -> impl Iterator<Item = C> + use<'a, F, T, C, Self>
+    where
+        Self: Sized,
+        T: 'a,
+        C::Unit: From<u8>,
+        F: Fn(&T, &T) -> bool + 'a,
+    {
+        let from = self.tile(position);
+        position.neighbors4().into_iter().filter_map(move |next| {
+            let to = self.tile(&next)?;
+            passable(from?, to).then_some(next)
+        })
+    }
+
+    fn print<F: Fn(C, Option<&T>) -> String>(&self, tile_printer: F)
+    where
+        Self: Sized,
+        C: Hash + Eq,
+        C::Unit: Display,
+    {
+        print!("{}", self.display(tile_printer));
+    }
+
+    /// Draws the grid's range as text, top row first, with `tile` giving the
+    /// text of each position.
+    fn render<F: Fn(C, Option<&T>) -> String>(&self, tile: F) -> String
+    where
+        Self: Sized,
+        C: Hash + Eq,
+        C::Unit: Display,
+    {
+        self.display(tile).to_string()
+    }
+
+    /// Like [`Tiles::render`], but returns a [`Render`] that can be given
+    /// overlays, axis labels or a crop before being displayed.
+    fn display<F: Fn(C, Option<&T>) -> String>(&self, tile: F) -> Render<'_, T, C, F>
+    where
+        Self: Sized,
+    {
+        Render::new(self, tile)
+    }
+}
+
+impl<T, C> Tiles<T, C> for Grid<T, C>
+where
+    C: Coordinate + Hash + Eq + Clone,
+    C::Unit: Step,
+{
+    fn tile(&self, position: &C) -> Option<&T> {
+        self.get(position)
+    }
+
+    fn tile_range(&self) -> &RectangularRange<C> {
+        self.range()
+    }
+}
+
+impl<T, C> Tiles<T, C> for DenseGrid<T, C>
+where
+    C: Coordinate,
+    C::Unit: Step,
+{
+    fn tile(&self, position: &C) -> Option<&T> {
+        self.get(position)
+    }
+
+    fn tile_range(&self) -> &RectangularRange<C> {
+        self.range()
+    }
+}
+
+/// A grid drawn as text, shown with `{}` or turned into a `String` with
+/// `to_string`:
+///
+/// ```
+/// use aoc::{
+///     coordinate::RowCol,
+///     grid::{Grid, Tiles},
+/// };
+///
+/// let grid = Grid::<char, RowCol>::parse("#.\n.#\n", |_, ch| ch);
+/// let text = grid
+///     .display(|_, t| t.unwrap().to_string())
+///     .overlay([RowCol::new(0, 1)], 'S')
+///     .axis_labels()
+///     .to_string();
+/// assert_eq!(text, "  01\n0 #S\n1 .#\n");
+/// ```
+pub struct Render<'g, T, C, F>
+where
+    C: Coordinate,
+    C::Unit: Step,
+{
+    grid: &'g dyn Tiles<T, C>,
+    tile: F,
+    range: RectangularRange<C>,
+    /// Drawn over the tiles, the last one on top.
+    overlays: Vec<(HashSet<C>, String)>,
+    axis_labels: bool,
+}
+
+impl<'g, T, C, F> Render<'g, T, C, F>
+where
+    C: Coordinate,
+    C::Unit: Step,
+{
+    fn new(grid: &'g dyn Tiles<T, C>, tile: F) -> Self {
+        Self {
+            grid,
+            tile,
+            range: grid.tile_range().clone(),
+            overlays: Vec::new(),
+            axis_labels: false,
+        }
+    }
+
+    /// Draws only `range`, which may also reach past the grid's own range.
+    pub fn crop(self, range: RectangularRange<C>) -> Self {
+        Self { range, ..self }
+    }
+
+    /// Numbers the rows down the left and the columns along the top, with
+    /// column numbers written downwards. Assumes one character per tile.
+    pub fn axis_labels(self) -> Self {
+        Self {
+            axis_labels: true,
+            ..self
+        }
+    }
+}
+
+impl<T, C, F> Render<'_, T, C, F>
+where
+    C: Coordinate + Hash + Eq,
+    C::Unit: Step,
+    F: Fn(C, Option<&T>) -> String,
+{
+    /// Draws `symbol` at `positions` instead of whatever is there.
+    pub fn overlay(mut self, positions: impl IntoIterator<Item = C>, symbol: impl Display) -> Self {
+        self.overlays
+            .push((positions.into_iter().collect(), symbol.to_string()));
+        self
+    }
+
+    fn text(&self, c: C) -> String {
+        match self.overlays.iter().rev().find(|(at, _)| at.contains(&c)) {
+            Some((_, symbol)) => symbol.clone(),
+            None => {
+                let t = self.grid.tile(&c);
+                (self.tile)(c, t)
+            }
+        }
+    }
+}
+
+impl<T, C, F> Display for Render<'_, T, C, F>
+where
+    C: Coordinate + Hash + Eq,
+    C::Unit: Step + Display,
+    F: Fn(C, Option<&T>) -> String,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let rows: Vec<_> = self.range.vertical().iter_top_top_bottom().collect();
+        let columns: Vec<_> = self.range.horizontal().iter_left_to_right().collect();
+
+        let mut margin = 0;
+        if self.axis_labels {
+            margin = rows.iter().map(|v| v.to_string().len()).max().unwrap_or(0) + 1;
+            let labels: Vec<Vec<char>> = columns
+                .iter()
+                .map(|h| h.to_string().chars().collect())
+                .collect();
+            let height = labels.iter().map(|l| l.len()).max().unwrap_or(0);
+            for line in 0..height {
+                // Aligned at the bottom, so units share the last line
+                let digits: String = labels
+                    .iter()
+                    .map(|l| (line + l.len()).checked_sub(height).map_or(' ', |i| l[i]))
+                    .collect();
+                writeln!(f, "{:margin$}{}", "", digits.trim_end())?;
+            }
+        }
+
+        for v in rows {
+            if self.axis_labels {
+                write!(f, "{:>width$} ", v, width = margin - 1)?;
+            }
+            for h in &columns {
+                f.write_str(&self.text(C::from_horz_vert(*h, v)))?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// How the text parsers fill in either kind of grid.
+trait Insert<T, C>: Default {
+    fn insert_tile(&mut self, position: C, tile: T);
+}
+
+impl<T, C> Insert<T, C> for Grid<T, C>
+where
+    C: Coordinate + Hash + Eq + Clone,
+    C::Unit: Step,
+{
+    fn insert_tile(&mut self, position: C, tile: T) {
+        self.insert(position, tile);
+    }
+}
+
+impl<T, C> Insert<T, C> for DenseGrid<T, C>
+where
+    C: Coordinate,
+    C::Unit: Step,
+{
+    fn insert_tile(&mut self, position: C, tile: T) {
+        self.insert(position, tile);
+    }
+}
+
+fn parse_tiles<G, T, C>(input: &str, mut tile: impl FnMut(C, char) -> T) -> G
+where
+    G: Insert<T, C>,
+    C: Coordinate,
+    C::Unit: Step,
+{
+    match try_parse_tiles(input, |c, ch| Ok::<_, Infallible>(tile(c, ch))) {
+        Ok(grid) => grid,
+        Err(never) => match never {},
+    }
+}
+
+fn try_parse_tiles<G, T, C, E>(
+    input: &str,
+    mut tile: impl FnMut(C, char) -> Result<T, E>,
+) -> Result<G, E>
+where
+    G: Insert<T, C>,
+    C: Coordinate,
+    C::Unit: Step,
+{
+    let mut grid = G::default();
+    for (c, ch) in text_positions::<C>(input) {
+        let t = tile(c.clone(), ch)?;
+        grid.insert_tile(c, t);
+    }
+    Ok(grid)
+}
+
+fn parse_tiles_with_markers<G, T, C>(
+    input: &str,
+    markers: &[char],
+    mut tile: impl FnMut(C, char) -> T,
+) -> (G, HashMap<char, Vec<C>>)
+where
+    G: Insert<T, C>,
+    C: Coordinate,
+    C::Unit: Step,
+{
+    let mut found: HashMap<char, Vec<C>> = HashMap::new();
+    let grid = parse_tiles(input, |c: C, ch| {
+        if markers.contains(&ch) {
+            found.entry(ch).or_default().push(c.clone());
+        }
+        tile(c, ch)
+    });
+    (grid, found)
+}
+
+/// Iterates the characters of puzzle text along with the position each one
+/// takes in a grid, with row 0 at the top and column 0 at the left.
+fn text_positions<C>(input: &str) -> impl Iterator<Item = (C, char)> + '_
+where
+    C: Coordinate,
+    C::Unit: Step,
+{
+    let origin = C::default();
+    let (h0, v0) = (*origin.horizontal(), *origin.vertical());
+    input.lines().enumerate().flat_map(move |(row, line)| {
+        let v = match C::VERTICAL_AXIS_ORIENTATION {
+            VerticalAxisOrientation::PositiveDown => Step::forward(v0, row),
+            VerticalAxisOrientation::PositiveUp => Step::backward(v0, row),
+        };
+        line.chars().enumerate().map(move |(col, ch)| {
+            let h = match C::HORIZONTAL_AXIS_ORIENTATION {
+                HorizontalAxisOrientation::PositiveRight => Step::forward(h0, col),
+                HorizontalAxisOrientation::PositiveLeft => Step::backward(h0, col),
+            };
+            (C::from_horz_vert(h, v), ch)
+        })
+    })
+}
+
+/// Number of steps between two points on an axis, regardless of their order.
+fn steps<U: Step>(a: &U, b: &U) -> usize {
+    if a <= b {
+        U::steps_between(a, b).1.unwrap()
+    } else {
+        U::steps_between(b, a).1.unwrap()
+    }
+}
+
+fn area<C>(range: &RectangularRange<C>) -> usize
+where
+    C: Coordinate,
+    C::Unit: Step,
+{
+    range.horizontal().count() * range.vertical().count()
+}
+
+/// Extends `range` to cover `point`, plus as much again as the range already
+/// spanned on the side that grew.
+fn padded<U: Step + Copy>(range: &Range<U>, point: &U) -> Range<U> {
+    let (start, end) = (*range.start().unwrap(), *range.end().unwrap());
+    let slack = range.count();
+    if *point < start {
+        let start = Step::backward_checked(*point, slack).unwrap_or(*point);
+        Range::new(start..=end)
+    } else if *point > end {
+        let end = Step::forward_checked(*point, slack).unwrap_or(*point);
+        Range::new(start..=end)
+    } else {
+        range.clone()
+    }
+}
+
+/// Iterates the positions of a range from the top row to the bottom, and left
+/// to right within each row.
+fn reading_order<C>(range: &RectangularRange<C>) -> impl Iterator<Item = C>
+where
+    C: Coordinate,
+    C::Unit: Step,
+{
+    let horizontal: Vec<_> = range.horizontal().iter_left_to_right().collect();
+    range.vertical().iter_top_top_bottom().flat_map(move |v| {
+        horizontal
+            .clone()
+            .into_iter()
+            .map(move |h| C::from_horz_vert(h, v))
+    })
+}
+
+impl<T> From<Vec<Vec<T>>> for DenseGrid<T, RowCol> {
+    fn from(value: Vec<Vec<T>>) -> Self {
+        let mut grid = Self::new();
+        for (r, row) in value.into_iter().enumerate() {
+            for (c, value) in row.into_iter().enumerate() {
+                grid.insert(RowCol::new(r as i32, c as i32), value);
             }
-            r += 1;
         }
         grid
     }
 }
+
+impl<T, C> From<Grid<T, C>> for DenseGrid<T, C>
+where
+    C: Coordinate + Hash + Eq,
+    C::Unit: Step,
+{
+    fn from(value: Grid<T, C>) -> Self {
+        let mut grid = Self::new_from_range(value.range);
+        for (c, t) in value.tiles {
+            grid.insert(c, t);
+        }
+        grid
+    }
+}
+
+impl<T, C> From<DenseGrid<T, C>> for Grid<T, C>
+where
+    C: Coordinate + Hash + Eq,
+    C::Unit: Step,
+{
+    fn from(value: DenseGrid<T, C>) -> Self {
+        let tiles = reading_order(&value.allocated)
+            .zip(value.tiles)
+            .filter_map(|(c, t)| t.map(|t| (c, t)))
+            .collect();
+        Self {
+            tiles,
+            range: value.range,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{DenseGrid, Grid, Tiles};
+    use crate::{
+        coordinate::{Coordinate, HorizontalRange, RectangularRange, RowCol, VerticalRange, XY},
+        range::Range,
+    };
+
+    #[test]
+    fn dense_grows_in_every_direction() {
+        let mut grid = DenseGrid::<char, XY>::new();
+        grid.insert(XY::new(0, 0), 'a');
+        grid.insert(XY::new(3, -2), 'b');
+        grid.insert(XY::new(-5, 7), 'c');
+        *grid.get_mut_or_default(&XY::new(1, 1)) = 'd';
+
+        assert_eq!(grid.get(&XY::new(0, 0)), Some(&'a'));
+        assert_eq!(grid.get(&XY::new(3, -2)), Some(&'b'));
+        assert_eq!(grid.get(&XY::new(-5, 7)), Some(&'c'));
+        assert_eq!(grid.get(&XY::new(1, 1)), Some(&'d'));
+        assert_eq!(grid.get(&XY::new(1, 0)), None);
+        assert_eq!(grid.get(&XY::new(100, 100)), None);
+
+        assert_eq!(grid.range().left(), Some(&-5));
+        assert_eq!(grid.range().right(), Some(&3));
+        assert_eq!(grid.range().top(), Some(&7));
+        assert_eq!(grid.range().bottom(), Some(&-2));
+        assert_eq!(grid.tiles().count(), 4);
+    }
+
+    #[test]
+    fn dense_tiles_in_reading_order() {
+        let grid = DenseGrid::from(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+        assert_eq!(
+            grid.tiles().cloned().collect::<Vec<_>>(),
+            [1, 2, 3, 4, 5, 6]
+        );
+        assert_eq!(grid.get(&RowCol::new(1, 0)), Some(&4));
+        assert_eq!(
+            grid.enumerate_tiles()
+                .map(|(c, t)| (c, t.cloned()))
+                .find(|(_, t)| *t == Some(6))
+                .map(|(c, _)| c),
+            Some(RowCol::new(1, 2))
+        );
+    }
+
+    #[test]
+    fn dense_enumerate_mut_fills_range() {
+        let mut grid = DenseGrid::<u32, RowCol>::new();
+        grid.insert(RowCol::new(0, 0), 1);
+        grid.insert(RowCol::new(2, 2), 1);
+        for (c, t) in grid.enumerate_tiles_mut() {
+            *t += *c.row() as u32;
+        }
+        assert_eq!(grid.tiles().count(), 9);
+        assert_eq!(grid.get(&RowCol::new(1, 1)), Some(&1));
+        assert_eq!(grid.get(&RowCol::new(2, 2)), Some(&3));
+    }
+
+    #[test]
+    fn dense_sparse_round_trip() {
+        let mut sparse = Grid::<&str, XY>::new();
+        sparse.insert(XY::new(2, 2), "x");
+        sparse.insert(XY::new(-1, 4), "y");
+
+        let dense = DenseGrid::from(sparse);
+        assert_eq!(dense.get(&XY::new(2, 2)), Some(&"x"));
+        assert_eq!(dense.get(&XY::new(-1, 4)), Some(&"y"));
+        assert_eq!(dense.get(&XY::new(0, 3)), None);
+
+        let sparse = Grid::from(dense);
+        assert_eq!(sparse.tiles().count(), 2);
+        assert_eq!(sparse.get(&XY::new(-1, 4)), Some(&"y"));
+        assert_eq!(*sparse.range().left().unwrap(), -1);
+        assert_eq!(sparse.range().top_left().unwrap().y(), &4);
+        assert_eq!(sparse.range().bottom_right().unwrap().horizontal(), &2);
+    }
+
+    #[test]
+    fn parse_places_first_line_at_top() {
+        let text = "ab\ncd\n";
+
+        let grid = Grid::<char, RowCol>::parse(text, |_, ch| ch);
+        assert_eq!(grid.get(&RowCol::new(0, 1)), Some(&'b'));
+        assert_eq!(grid.get(&RowCol::new(1, 0)), Some(&'c'));
+
+        let grid = DenseGrid::<char, XY>::parse(text, |_, ch| ch);
+        assert_eq!(grid.range().top_left(), Some(XY::new(0, 0)));
+        assert_eq!(grid.get(&XY::new(1, 0)), Some(&'b'));
+        assert_eq!(grid.get(&XY::new(0, -1)), Some(&'c'));
+    }
+
+    #[test]
+    fn parse_with_markers() {
+        let (grid, markers) =
+            DenseGrid::<u32, RowCol>::parse_with_markers("S12\n3E4", &['S', 'E'], |_, ch| {
+                ch.to_digit(10).unwrap_or(0)
+            });
+        assert_eq!(markers[&'S'], [RowCol::new(0, 0)]);
+        assert_eq!(markers[&'E'], [RowCol::new(1, 1)]);
+        assert_eq!(grid.get(&RowCol::new(1, 2)), Some(&4));
+        assert_eq!(grid.get(&RowCol::new(1, 1)), Some(&0));
+
+        let failed = Grid::<u32, RowCol>::try_parse("12\n3x", |c, ch| ch.to_digit(10).ok_or(c));
+        assert_eq!(failed.err(), Some(RowCol::new(1, 1)));
+    }
+
+    #[test]
+    fn render_with_overlays() {
+        let mut grid = Grid::<char, XY>::new();
+        grid.insert(XY::new(0, 0), '#');
+        grid.insert(XY::new(2, 1), '#');
+        let tile = |_, t: Option<&char>| t.copied().unwrap_or('.').to_string();
+        assert_eq!(grid.render(tile), "..#\n#..\n");
+
+        let text = grid
+            .display(tile)
+            .overlay([XY::new(1, 0), XY::new(2, 1)], 'o')
+            .overlay([XY::new(1, 0)], "@")
+            .to_string();
+        assert_eq!(text, "..o\n#@.\n");
+
+        let dense = DenseGrid::from(grid);
+        let cropped = dense
+            .display(tile)
+            .crop(RectangularRange::new(
+                HorizontalRange::new(Range::new(-1..=0)),
+                VerticalRange::new(Range::new(-1..=0)),
+            ))
+            .to_string();
+        assert_eq!(cropped, ".#\n..\n");
+    }
+
+    #[test]
+    fn render_axis_labels() {
+        let grid = Grid::<char, RowCol>::parse("abc\ndef\n", |_, ch| ch);
+        let range = RectangularRange::new(
+            HorizontalRange::new(Range::new(8..=11)),
+            VerticalRange::new(Range::new(0..=1)),
+        );
+        let mut moved = Grid::<char, RowCol>::new_from_range(range);
+        for (c, t) in grid.enumerate_tiles() {
+            moved.insert(RowCol::new(*c.row(), c.col() + 9), *t.unwrap());
+        }
+        let text = moved
+            .display(|_, t| t.copied().unwrap_or(' ').to_string())
+            .axis_labels()
+            .to_string();
+        assert_eq!(text, "    11\n  8901\n0  abc\n1  def\n");
+    }
+}
@@ -0,0 +1,197 @@
+use std::{
+    collections::HashMap,
+    hash::Hash,
+    iter::Step,
+    ops::{Add, Sub},
+};
+
+use crate::{
+    coordinate3d::{CuboidRange, XYZ},
+    GetOrDefault,
+};
+
+/// A sparse grid of [`XYZ`] positions, the 3D counterpart of
+/// [`crate::grid::Grid`].
+pub struct Grid3d<T, U = i32> {
+    tiles: HashMap<XYZ<U>, T>,
+    range: CuboidRange<U>,
+}
+
+impl<T, U> Default for Grid3d<T, U>
+where
+    U: Copy + PartialOrd + Hash + Eq,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, U> Grid3d<T, U>
+where
+    U: Copy + PartialOrd + Hash + Eq,
+{
+    pub fn new() -> Self {
+        Self {
+            tiles: HashMap::new(),
+            range: CuboidRange::empty(),
+        }
+    }
+
+    pub fn range(&self) -> &CuboidRange<U> {
+        &self.range
+    }
+
+    pub fn len(&self) -> usize {
+        self.tiles.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tiles.is_empty()
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = &XYZ<U>> {
+        self.tiles.keys()
+    }
+
+    pub fn tiles(&self) -> impl Iterator<Item = &T> {
+        self.tiles.values()
+    }
+
+    pub fn tiles_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.tiles.values_mut()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&XYZ<U>, &T)> {
+        self.tiles.iter()
+    }
+
+    pub fn enumerate_tiles(&self) -> impl Iterator<Item = (XYZ<U>, Option<&T>)>
+    where
+        U: Step + 'static,
+    {
+        self.range.iter().map(|c| {
+            let t = self.get(&c);
+            (c, t)
+        })
+    }
+
+    pub fn contains(&self, position: &XYZ<U>) -> bool {
+        self.tiles.contains_key(position)
+    }
+
+    pub fn get(&self, position: &XYZ<U>) -> Option<&T> {
+        self.tiles.get(position)
+    }
+
+    pub fn get_mut(&mut self, position: &XYZ<U>) -> Option<&mut T> {
+        self.tiles.get_mut(position)
+    }
+
+    pub fn get_mut_or_default(&mut self, position: &XYZ<U>) -> &mut T
+    where
+        T: Default,
+    {
+        self.range.extend(position);
+        self.tiles.get_mut_or_default(position)
+    }
+
+    pub fn insert(&mut self, position: XYZ<U>, tile: T) -> Option<T> {
+        self.range.extend(&position);
+        self.tiles.insert(position, tile)
+    }
+
+    /// Removes a tile. The range is left as it was.
+    pub fn remove(&mut self, position: &XYZ<U>) -> Option<T> {
+        self.tiles.remove(position)
+    }
+
+    /// The face neighbors of `position` that hold a tile.
+    pub fn occupied_neighbors6<'a>(
+        &'a self,
+        position: &XYZ<U>,
+    ) -> impl Iterator<Item = (XYZ<U>, &'a T)> + 'a
+    where
+        U: Add<U, Output = U> + Sub<U, Output = U> + From<u8>,
+    {
+        position
+            .neighbors6()
+            .into_iter()
+            .filter_map(|n| self.get(&n).map(|t| (n, t)))
+    }
+}
+
+impl<T, U> FromIterator<(XYZ<U>, T)> for Grid3d<T, U>
+where
+    U: Copy + PartialOrd + Hash + Eq,
+{
+    fn from_iter<I: IntoIterator<Item = (XYZ<U>, T)>>(iter: I) -> Self {
+        let mut grid = Self::new();
+        for (position, tile) in iter {
+            grid.insert(position, tile);
+        }
+        grid
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Grid3d;
+    use crate::{coordinate3d::XYZ, search};
+
+    // The lava droplet from AoC 2022 day 18
+    const DROPLET: &str = "2,2,2
+1,2,2
+3,2,2
+2,1,2
+2,3,2
+2,2,1
+2,2,3
+2,2,4
+2,2,6
+1,2,5
+3,2,5
+2,1,5
+2,3,5";
+
+    fn droplet() -> Grid3d<()> {
+        DROPLET
+            .lines()
+            .map(|line| {
+                let v: Vec<i32> = line.split(',').map(|n| n.parse().unwrap()).collect();
+                (XYZ::new(v[0], v[1], v[2]), ())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn surface_area() {
+        let droplet = droplet();
+        assert_eq!(droplet.len(), 13);
+
+        let exposed: usize = droplet
+            .positions()
+            .map(|p| 6 - droplet.occupied_neighbors6(p).count())
+            .sum();
+        assert_eq!(exposed, 64);
+    }
+
+    #[test]
+    fn exterior_surface_area() {
+        let droplet = droplet();
+        let bounds = droplet.range().expanded(1);
+
+        let outside = search::bfs(bounds.min().unwrap(), |p| {
+            p.neighbors6()
+                .into_iter()
+                .filter(|n| bounds.contains(n) && !droplet.contains(n))
+                .collect::<Vec<_>>()
+        });
+
+        let exterior = droplet
+            .positions()
+            .flat_map(|p| p.neighbors6())
+            .filter(|n| outside.distance(n).is_some())
+            .count();
+        assert_eq!(exterior, 58);
+    }
+}
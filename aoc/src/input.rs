@@ -0,0 +1,365 @@
+//! Splitting inputs made of several sections, separated by blank lines, and
+//! reading the drawings some of those sections hold.
+
+use nom::{error::VerboseError, Parser};
+
+use crate::{
+    error::{parse_lines, LineError, ParseError},
+    parse,
+};
+
+/// One section of an input, between blank lines.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Block<'a> {
+    /// The line of the whole input the block starts on, counted from 1.
+    pub line: usize,
+    /// The block's lines, without the newline after the last one.
+    pub text: &'a str,
+}
+
+impl<'a> Block<'a> {
+    pub fn lines(&self) -> std::str::Lines<'a> {
+        self.text.lines()
+    }
+
+    /// [`parse_lines`] over the block, with errors numbered by their line in
+    /// the whole input.
+    pub fn parse_lines<T>(
+        &self,
+        parse: impl FnMut(&str) -> Result<T, LineError>,
+    ) -> Result<Vec<T>, ParseError> {
+        parse_lines(self.text, parse).map_err(|e| self.in_input(e))
+    }
+
+    /// [`parse::parse_all`] over the block, with errors numbered by their
+    /// line in the whole input.
+    pub fn parse_all<O>(
+        &self,
+        parser: impl Parser<&'a str, O, VerboseError<&'a str>>,
+    ) -> Result<O, ParseError> {
+        parse::parse_all(parser, self.text).map_err(|e| self.in_input(e))
+    }
+
+    fn in_input(&self, e: ParseError) -> ParseError {
+        ParseError {
+            line: e.line + self.line - 1,
+            ..e
+        }
+    }
+}
+
+/// The sections of `input` separated by one or more blank lines. Lines of
+/// only whitespace count as blank, `\r\n` line endings are accepted, and
+/// blank lines at the start or end yield no empty blocks.
+pub fn blocks(input: &str) -> impl Iterator<Item = Block<'_>> {
+    let mut rest = input;
+    let mut line = 1;
+    std::iter::from_fn(move || {
+        // Skip blank lines before the block
+        loop {
+            if rest.is_empty() {
+                return None;
+            }
+            let end = rest.find('\n').map_or(rest.len(), |n| n + 1);
+            if !rest[..end].trim().is_empty() {
+                break;
+            }
+            rest = &rest[end..];
+            line += 1;
+        }
+
+        let start_line = line;
+        let mut length = 0;
+        while length < rest.len() {
+            let end = rest[length..]
+                .find('\n')
+                .map_or(rest.len(), |n| length + n + 1);
+            if rest[length..end].trim().is_empty() {
+                break;
+            }
+            length = end;
+            line += 1;
+        }
+        let text = rest[..length].trim_end_matches(['\n', '\r']);
+        rest = &rest[length..];
+        Some(Block {
+            line: start_line,
+            text,
+        })
+    })
+}
+
+/// The lines of a block, read one at a time by [`FromBlock`].
+pub struct BlockLines<'a> {
+    lines: std::str::Lines<'a>,
+    /// The line last read, counted from 1 within the block.
+    number: usize,
+    current: &'a str,
+}
+
+impl<'a> BlockLines<'a> {
+    pub fn new(text: &'a str) -> Self {
+        Self {
+            lines: text.lines(),
+            number: 0,
+            current: "",
+        }
+    }
+
+    /// The next line, whatever it holds.
+    pub fn next_line(&mut self) -> Result<&'a str, LineError> {
+        let line = self
+            .lines
+            .next()
+            .ok_or_else(|| LineError::new("the block ended early"))?;
+        self.number += 1;
+        self.current = line;
+        Ok(line)
+    }
+
+    /// The rest of the next line after `prefix`, ignoring indentation and
+    /// the space after the prefix.
+    pub fn line(&mut self, prefix: &str) -> Result<&'a str, LineError> {
+        let line = self.next_line()?;
+        let trimmed = line.trim_start();
+        match trimmed.strip_prefix(prefix) {
+            Some(rest) => Ok(rest.trim()),
+            None => {
+                let column = line.len() - trimmed.len() + 1;
+                Err(LineError::new(format!("expected '{}'", prefix)).at(column))
+            }
+        }
+    }
+
+    /// Fails unless every line has been read.
+    pub fn end(&mut self) -> Result<(), LineError> {
+        match self.lines.next() {
+            Some(line) => {
+                self.number += 1;
+                self.current = line;
+                Err(LineError::new("expected the end of the block"))
+            }
+            None => Ok(()),
+        }
+    }
+}
+
+/// A record that takes up a block of input, read line by line:
+///
+/// ```
+/// use aoc::{
+///     error::LineError,
+///     input::{parse_blocks, BlockLines, FromBlock},
+/// };
+///
+/// struct Elf {
+///     name: String,
+///     calories: u32,
+/// }
+///
+/// impl FromBlock for Elf {
+///     fn from_block(lines: &mut BlockLines) -> Result<Self, LineError> {
+///         Ok(Elf {
+///             name: lines.line("Elf")?.to_string(),
+///             calories: lines.line("Calories:")?.parse()?,
+///         })
+///     }
+/// }
+///
+/// let elves: Vec<Elf> = parse_blocks("Elf Ann\nCalories: 10\n\nElf Bob\nCalories: 20\n").unwrap();
+/// assert_eq!(elves[1].calories, 20);
+/// ```
+pub trait FromBlock: Sized {
+    fn from_block(lines: &mut BlockLines) -> Result<Self, LineError>;
+}
+
+/// Parses every block of `input` as a `T`. A failure points at the line of
+/// the input it happened on.
+pub fn parse_blocks<T: FromBlock>(input: &str) -> Result<Vec<T>, ParseError> {
+    blocks(input)
+        .map(|block| {
+            let mut lines = BlockLines::new(block.text);
+            T::from_block(&mut lines)
+                .and_then(|record| lines.end().map(|_| record))
+                .map_err(|e| ParseError {
+                    file: None,
+                    line: block.line + lines.number.max(1) - 1,
+                    column: e.column(),
+                    text: lines.current.to_string(),
+                    message: e.message().to_string(),
+                })
+        })
+        .collect()
+}
+
+/// The stacks of a drawing like
+///
+/// ```text
+///     [D]
+/// [N] [C]
+/// [Z] [M] [P]
+///  1   2   3
+/// ```
+///
+/// with the labels of its footer line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Columns<'a> {
+    pub labels: Vec<&'a str>,
+    /// One stack per label, from the bottom of the drawing up. Brackets
+    /// around an item are left out.
+    pub stacks: Vec<Vec<&'a str>>,
+}
+
+// The words of a line with their character span, as (start, end, word)
+fn words(line: &str) -> Vec<(usize, usize, &str)> {
+    let mut words = Vec::new();
+    let mut start = None;
+    for (column, (index, c)) in line.char_indices().chain([(line.len(), ' ')]).enumerate() {
+        match (start, c.is_whitespace()) {
+            (None, false) => start = Some((column, index)),
+            (Some((first, from)), true) => {
+                words.push((first, column, &line[from..index]));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    words
+}
+
+/// Reads a drawing of stacked items standing on a footer of labels. Each
+/// item belongs to the label its middle is closest to, so items and labels
+/// may be several characters wide and lines may be ragged. Line numbers in
+/// errors count from the first line of `drawing`.
+pub fn columns(drawing: &str) -> Result<Columns<'_>, ParseError> {
+    let lines: Vec<&str> = drawing.lines().map(|l| l.trim_end_matches('\r')).collect();
+    let error = |line: usize, column: Option<usize>, message: &str| ParseError {
+        file: None,
+        line: line + 1,
+        column: column.map(|c| c + 1),
+        text: lines.get(line).unwrap_or(&"").to_string(),
+        message: message.to_string(),
+    };
+
+    let Some((footer, rows)) = lines.split_last() else {
+        return Err(error(0, None, "the drawing is empty"));
+    };
+    let labels = words(footer);
+    if labels.is_empty() {
+        return Err(error(rows.len(), None, "expected a footer of labels"));
+    }
+    // Twice the middle, to keep to whole numbers
+    let middles: Vec<usize> = labels.iter().map(|(start, end, _)| start + end).collect();
+
+    let mut stacks = vec![Vec::new(); labels.len()];
+    for (number, row) in rows.iter().enumerate().rev() {
+        let mut filled = vec![false; labels.len()];
+        for (start, end, word) in words(row) {
+            let (index, _) = middles
+                .iter()
+                .enumerate()
+                .min_by_key(|(_, middle)| middle.abs_diff(start + end))
+                .unwrap();
+            if filled[index] {
+                return Err(error(number, Some(start), "two items above one label"));
+            }
+            filled[index] = true;
+            let item = word
+                .strip_prefix('[')
+                .and_then(|w| w.strip_suffix(']'))
+                .unwrap_or(word);
+            stacks[index].push(item);
+        }
+    }
+
+    Ok(Columns {
+        labels: labels.into_iter().map(|(_, _, label)| label).collect(),
+        stacks,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::{blocks, columns, parse_blocks, BlockLines, FromBlock};
+    use crate::error::LineError;
+
+    #[test]
+    fn blank_lines_separate_blocks() {
+        let found: Vec<_> = blocks("\na\nb\n\n \n c\r\nd\r\n\r\ne\n\n")
+            .map(|b| (b.line, b.text))
+            .collect();
+        assert_eq!(found, [(2, "a\nb"), (6, " c\r\nd"), (9, "e")]);
+        assert_eq!(blocks("").count(), 0);
+        assert_eq!(blocks("x").map(|b| b.text).collect::<Vec<_>>(), ["x"]);
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Move {
+        count: usize,
+        to: String,
+    }
+
+    impl FromBlock for Move {
+        fn from_block(lines: &mut BlockLines) -> Result<Self, LineError> {
+            Ok(Move {
+                count: lines.line("Count:")?.parse()?,
+                to: lines.line("To:")?.to_string(),
+            })
+        }
+    }
+
+    #[test]
+    fn records_from_blocks() {
+        let moves: Vec<Move> = parse_blocks("Count: 3\n  To: left\n\nCount: 1\nTo: up").unwrap();
+        assert_eq!(
+            moves,
+            [
+                Move {
+                    count: 3,
+                    to: "left".to_string()
+                },
+                Move {
+                    count: 1,
+                    to: "up".to_string()
+                }
+            ]
+        );
+
+        let e = parse_blocks::<Move>("Count: 3\nTo: left\n\nCount: x\nTo: up").unwrap_err();
+        assert_eq!((e.line, e.text.as_str()), (4, "Count: x"));
+        let e = parse_blocks::<Move>("Count: 3\n  From: left\n").unwrap_err();
+        assert_eq!((e.line, e.column), (2, Some(3)));
+        assert_eq!(e.message, "expected 'To:'");
+        let e = parse_blocks::<Move>("Count: 3\nTo: left\nTo: right\n").unwrap_err();
+        assert_eq!((e.line, e.text.as_str()), (3, "To: right"));
+        let e = parse_blocks::<Move>("Count: 3\n").unwrap_err();
+        assert_eq!(e.message, "the block ended early");
+    }
+
+    #[test]
+    fn drawn_columns() {
+        let drawing = columns("    [D]    \n[N] [C]\n[Z] [M] [P]\n 1   2   3 \n").unwrap();
+        assert_eq!(drawing.labels, ["1", "2", "3"]);
+        assert_eq!(
+            drawing.stacks,
+            [vec!["Z", "N"], vec!["M", "C", "D"], vec!["P"]]
+        );
+
+        let drawing = columns("          [JK]\n[AB]      [C]\n  9   10   11\n").unwrap();
+        assert_eq!(drawing.labels, ["9", "10", "11"]);
+        assert_eq!(drawing.stacks, [vec!["AB"], vec![], vec!["C", "JK"]]);
+
+        let e = columns("[A] [B]\n 1\n").unwrap_err();
+        assert_eq!((e.line, e.column), (1, Some(5)));
+        assert!(columns("").is_err());
+    }
+
+    #[test]
+    fn block_lines_keep_their_numbers() {
+        let block = blocks("a\n\n1\nx\n").nth(1).unwrap();
+        let e = block
+            .parse_lines(|line| Ok(line.parse::<u8>()?))
+            .unwrap_err();
+        assert_eq!((e.line, e.text.as_str()), (4, "x"));
+    }
+}
@@ -2,15 +2,30 @@
 
 use std::collections::HashMap;
 
+pub mod answers;
+pub mod bitset;
 pub mod coordinate;
+pub mod coordinate3d;
+pub mod cycle;
+pub mod error;
+pub mod graph;
 pub mod grid;
+pub mod grid3d;
+pub mod input;
+pub mod memo;
+pub mod ocr;
+pub mod optimize;
+pub mod parse;
 pub mod position;
 pub mod range;
+pub mod range_set;
+pub mod search;
+pub mod solution;
 
-pub fn load_input(base: &str, path: &str) -> String {
+pub fn load_input(base: &str, path: &str) -> error::Result<String> {
     let path = std::path::Path::new(base).join(path);
 
-    std::fs::read_to_string(path).unwrap()
+    std::fs::read_to_string(&path).map_err(|e| error::Error::io(&path, e))
 }
 
 pub trait GetOrDefault<K, T>
@@ -0,0 +1,270 @@
+//! Caching the results of recursive functions, so that each distinct call is
+//! only worked out once.
+
+use std::{collections::HashMap, fmt::Display, hash::Hash};
+
+/// How often a [`Memo`] already had the answer.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Stats {
+    pub hits: usize,
+    pub misses: usize,
+}
+
+impl Stats {
+    /// The share of lookups that were hits, from 0 to 1.
+    pub fn hit_rate(&self) -> f64 {
+        match self.hits + self.misses {
+            0 => 0.0,
+            lookups => self.hits as f64 / lookups as f64,
+        }
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} hits, {} misses ({:.1}% hit rate)",
+            self.hits,
+            self.misses,
+            self.hit_rate() * 100.0
+        )
+    }
+}
+
+/// The values worked out so far, by their key.
+///
+/// ```
+/// use aoc::memo::Memo;
+///
+/// fn fibonacci(memo: &mut Memo<u64, u64>, n: u64) -> u64 {
+///     memo.get_or_compute(n, |memo, &n| match n {
+///         0 | 1 => n,
+///         _ => fibonacci(memo, n - 1) + fibonacci(memo, n - 2),
+///     })
+/// }
+///
+/// let mut memo = Memo::new();
+/// assert_eq!(fibonacci(&mut memo, 90), 2880067194370816120);
+/// assert_eq!(memo.stats().misses, 91);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Memo<K, V> {
+    values: HashMap<K, V>,
+    stats: Stats,
+}
+
+impl<K, V> Default for Memo<K, V> {
+    fn default() -> Self {
+        Self {
+            values: HashMap::new(),
+            stats: Stats::default(),
+        }
+    }
+}
+
+impl<K, V> Memo<K, V>
+where
+    K: Hash + Eq,
+    V: Clone,
+{
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The value for `key`, worked out by `compute` if it isn't known yet.
+    /// `compute` is given the memo back, to look up the values it depends on.
+    pub fn get_or_compute(&mut self, key: K, compute: impl FnOnce(&mut Self, &K) -> V) -> V {
+        if let Some(value) = self.lookup(&key) {
+            return value;
+        }
+        let value = compute(self, &key);
+        self.insert(key, value.clone());
+        value
+    }
+
+    /// The value for `key` if it is known, counted as a hit or a miss.
+    pub fn lookup(&mut self, key: &K) -> Option<V> {
+        let value = self.values.get(key).cloned();
+        match value {
+            Some(_) => self.stats.hits += 1,
+            None => self.stats.misses += 1,
+        }
+        value
+    }
+
+    pub fn insert(&mut self, key: K, value: V) {
+        self.values.insert(key, value);
+    }
+
+    pub fn stats(&self) -> Stats {
+        self.stats
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Forgets every value, and the stats with them.
+    pub fn clear(&mut self) {
+        self.values.clear();
+        self.stats = Stats::default();
+    }
+}
+
+/// Caches the results of a function by its arguments, which must be owned
+/// values that are `Clone + Hash + Eq`. The return value must be `Clone`.
+///
+/// A `#[memo(NAME)]` before the function names the thread-local
+/// `RefCell<Memo>` holding the cache, for looking at its stats or clearing it.
+///
+/// ```
+/// aoc::memoize! {
+///     #[memo(PATHS)]
+///     /// The lattice paths from (0, 0) to (x, y).
+///     fn paths(x: u32, y: u32) -> u64 {
+///         if x == 0 || y == 0 {
+///             return 1;
+///         }
+///         paths(x - 1, y) + paths(x, y - 1)
+///     }
+/// }
+///
+/// assert_eq!(paths(16, 16), 601080390);
+/// assert_eq!(PATHS.with_borrow(|memo| memo.len()), 16 * 16 + 2 * 16);
+/// println!("{}", PATHS.with_borrow(|memo| memo.stats()));
+/// ```
+#[macro_export]
+macro_rules! memoize {
+    (
+        #[memo($memo:ident)]
+        $(#[$attr:meta])*
+        $vis:vis fn $name:ident($($arg:ident: $ty:ty),* $(,)?) -> $ret:ty $body:block
+    ) => {
+        ::std::thread_local! {
+            $vis static $memo: ::std::cell::RefCell<$crate::memo::Memo<($($ty,)*), $ret>> =
+                ::std::cell::RefCell::new($crate::memo::Memo::new());
+        }
+
+        $(#[$attr])*
+        $vis fn $name($($arg: $ty),*) -> $ret {
+            fn compute($($arg: $ty),*) -> $ret $body
+
+            let key = ($($arg.clone(),)*);
+            if let Some(value) = $memo.with_borrow_mut(|memo| memo.lookup(&key)) {
+                return value;
+            }
+            // The cache mustn't be borrowed while computing, as that recurses
+            let value = compute($($arg),*);
+            $memo.with_borrow_mut(|memo| memo.insert(key, value.clone()));
+            value
+        }
+    };
+    (
+        $(#[$attr:meta])*
+        $vis:vis fn $name:ident($($arg:ident: $ty:ty),* $(,)?) -> $ret:ty $body:block
+    ) => {
+        $(#[$attr])*
+        $vis fn $name($($arg: $ty),*) -> $ret {
+            $crate::memoize! {
+                #[memo(MEMO)]
+                fn memoized($($arg: $ty),*) -> $ret $body
+            }
+            memoized($($arg),*)
+        }
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Memo, Stats};
+
+    crate::memoize! {
+        #[memo(ARRANGEMENTS)]
+        // Ways to fill `springs` with the runs of damaged springs in `groups`
+        fn arrangements(springs: Vec<u8>, groups: Vec<usize>) -> u64 {
+            let Some((&first, rest)) = springs.split_first() else {
+                return groups.is_empty() as u64;
+            };
+            let mut count = 0;
+            if first != b'#' {
+                count += arrangements(rest.to_vec(), groups.clone());
+            }
+            if let Some((&run, others)) = groups.split_first() {
+                let fits = springs.len() >= run
+                    && !springs[..run].contains(&b'.')
+                    && springs.get(run) != Some(&b'#');
+                if first != b'.' && fits {
+                    let after = springs.get(run + 1..).unwrap_or_default();
+                    count += arrangements(after.to_vec(), others.to_vec());
+                }
+            }
+            count
+        }
+    }
+
+    crate::memoize! {
+        fn collatz_steps(n: u64) -> u32 {
+            match n {
+                1 => 0,
+                n if n % 2 == 0 => 1 + collatz_steps(n / 2),
+                n => 1 + collatz_steps(3 * n + 1),
+            }
+        }
+    }
+
+    #[test]
+    fn recursion_through_the_memo() {
+        fn partitions(memo: &mut Memo<(u32, u32), u64>, n: u32, largest: u32) -> u64 {
+            memo.get_or_compute((n, largest), |memo, &(n, largest)| {
+                if n == 0 {
+                    return 1;
+                }
+                (1..=largest.min(n))
+                    .map(|part| partitions(memo, n - part, part))
+                    .sum()
+            })
+        }
+
+        let mut memo = Memo::new();
+        assert_eq!(partitions(&mut memo, 5, 5), 7);
+        assert_eq!(partitions(&mut memo, 100, 100), 190569292);
+        let stats = memo.stats();
+        assert_eq!(stats.misses, memo.len());
+        assert!(stats.hits > stats.misses);
+
+        memo.clear();
+        assert_eq!(memo.stats(), Stats::default());
+        assert!(memo.is_empty());
+    }
+
+    #[test]
+    fn memoized_functions() {
+        let springs = |s: &str| s.as_bytes().to_vec();
+        assert_eq!(arrangements(springs("???.###"), vec![1, 1, 3]), 1);
+        assert_eq!(arrangements(springs("?###????????"), vec![3, 2, 1]), 10);
+
+        let unfolded = ["?###????????"; 5].join("?");
+        assert_eq!(
+            arrangements(springs(&unfolded), [3, 2, 1].repeat(5)),
+            506250
+        );
+        let stats = ARRANGEMENTS.with_borrow(|memo| memo.stats());
+        assert!(stats.hits > 0);
+        assert_eq!(stats.misses, ARRANGEMENTS.with_borrow(|memo| memo.len()));
+
+        assert_eq!(collatz_steps(27), 111);
+        assert_eq!(collatz_steps(97), 118);
+    }
+
+    #[test]
+    fn stats_display() {
+        let stats = Stats { hits: 3, misses: 1 };
+        assert_eq!(stats.to_string(), "3 hits, 1 misses (75.0% hit rate)");
+        assert_eq!(Stats::default().hit_rate(), 0.0);
+    }
+}
@@ -0,0 +1,293 @@
+//! Reading the block letters some puzzles draw as their answer, like the CRT
+//! of 2022 day 10.
+//!
+//! Lit pixels are `#` or `█`, anything else is dark. Letters are found by the
+//! dark columns between them, and the font is picked by the height of the
+//! picture: 6 rows for the small font and 10 for the large one.
+
+use std::{fmt::Display, hash::Hash, iter::Step};
+
+use crate::{coordinate::Coordinate, grid::Tiles};
+
+/// The font of letters 6 pixels tall, most of them 4 wide.
+const SMALL: [(char, &str); 18] = [
+    ('A', ".##.\n#..#\n#..#\n####\n#..#\n#..#"),
+    ('B', "###.\n#..#\n###.\n#..#\n#..#\n###."),
+    ('C', ".##.\n#..#\n#...\n#...\n#..#\n.##."),
+    ('E', "####\n#...\n###.\n#...\n#...\n####"),
+    ('F', "####\n#...\n###.\n#...\n#...\n#..."),
+    ('G', ".##.\n#..#\n#...\n#.##\n#..#\n.###"),
+    ('H', "#..#\n#..#\n####\n#..#\n#..#\n#..#"),
+    ('I', "###\n.#.\n.#.\n.#.\n.#.\n###"),
+    ('J', "..##\n...#\n...#\n...#\n#..#\n.##."),
+    ('K', "#..#\n#.#.\n##..\n#.#.\n#.#.\n#..#"),
+    ('L', "#...\n#...\n#...\n#...\n#...\n####"),
+    ('O', ".##.\n#..#\n#..#\n#..#\n#..#\n.##."),
+    ('P', "###.\n#..#\n#..#\n###.\n#...\n#..."),
+    ('R', "###.\n#..#\n#..#\n###.\n#.#.\n#..#"),
+    ('S', ".###\n#...\n#...\n.##.\n...#\n###."),
+    ('U', "#..#\n#..#\n#..#\n#..#\n#..#\n.##."),
+    ('Y', "#...#\n#...#\n.#.#.\n..#..\n..#..\n..#.."),
+    ('Z', "####\n...#\n..#.\n.#..\n#...\n####"),
+];
+
+/// The font of letters 10 pixels tall and 6 wide.
+const LARGE: [(char, &str); 15] = [
+    (
+        'A',
+        "..##..\n.#..#.\n#....#\n#....#\n#....#\n######\n#....#\n#....#\n#....#\n#....#",
+    ),
+    (
+        'B',
+        "#####.\n#....#\n#....#\n#....#\n#####.\n#....#\n#....#\n#....#\n#....#\n#####.",
+    ),
+    (
+        'C',
+        ".####.\n#....#\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#....#\n.####.",
+    ),
+    (
+        'E',
+        "######\n#.....\n#.....\n#.....\n#####.\n#.....\n#.....\n#.....\n#.....\n######",
+    ),
+    (
+        'F',
+        "######\n#.....\n#.....\n#.....\n#####.\n#.....\n#.....\n#.....\n#.....\n#.....",
+    ),
+    (
+        'G',
+        ".####.\n#....#\n#.....\n#.....\n#.....\n#..###\n#....#\n#....#\n#...##\n.###.#",
+    ),
+    (
+        'H',
+        "#....#\n#....#\n#....#\n#....#\n######\n#....#\n#....#\n#....#\n#....#\n#....#",
+    ),
+    (
+        'J',
+        "...###\n....#.\n....#.\n....#.\n....#.\n....#.\n....#.\n#...#.\n#...#.\n.###..",
+    ),
+    (
+        'K',
+        "#....#\n#...#.\n#..#..\n#.#...\n##....\n##....\n#.#...\n#..#..\n#...#.\n#....#",
+    ),
+    (
+        'L',
+        "#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n######",
+    ),
+    (
+        'N',
+        "#....#\n##...#\n##...#\n#.#..#\n#.#..#\n#..#.#\n#..#.#\n#...##\n#...##\n#....#",
+    ),
+    (
+        'P',
+        "#####.\n#....#\n#....#\n#....#\n#####.\n#.....\n#.....\n#.....\n#.....\n#.....",
+    ),
+    (
+        'R',
+        "#####.\n#....#\n#....#\n#....#\n#####.\n#..#..\n#...#.\n#...#.\n#....#\n#....#",
+    ),
+    (
+        'X',
+        "#....#\n#....#\n.#..#.\n.#..#.\n..##..\n..##..\n.#..#.\n.#..#.\n#....#\n#....#",
+    ),
+    (
+        'Z',
+        "######\n.....#\n.....#\n....#.\n...#..\n..#...\n.#....\n#.....\n#.....\n######",
+    ),
+];
+
+/// Why a picture couldn't be read.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OcrError {
+    /// The picture isn't as tall as either font, ignoring dark rows above and
+    /// below it.
+    Height(usize),
+    /// Some glyphs aren't letters of the font.
+    Unrecognized {
+        /// The letters that were read, with `?` for each unknown glyph.
+        read: String,
+        glyphs: Vec<Glyph>,
+    },
+}
+
+/// A glyph of a picture, drawn with `#` and `.`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Glyph {
+    /// The column of the picture the glyph starts at, counted from 1.
+    pub column: usize,
+    pub picture: String,
+}
+
+impl Display for OcrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OcrError::Height(height) => write!(
+                f,
+                "expected letters 6 or 10 pixels tall, not {} pixels",
+                height
+            ),
+            OcrError::Unrecognized { read, glyphs } => {
+                write!(f, "unrecognized letters in \"{}\"", read)?;
+                for glyph in glyphs {
+                    write!(f, "\n  at column {}:", glyph.column)?;
+                    for line in glyph.picture.lines() {
+                        write!(f, "\n    {}", line)?;
+                    }
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for OcrError {}
+
+fn lit(ch: char) -> bool {
+    ch == '#' || ch == '█'
+}
+
+/// Reads the letters drawn in `picture`, one line per row of pixels.
+///
+/// ```
+/// let picture = [
+///     "#..#.###",
+///     "#..#..#.",
+///     "####..#.",
+///     "#..#..#.",
+///     "#..#..#.",
+///     "#..#.###",
+/// ];
+/// assert_eq!(aoc::ocr::read(&picture.join("\n")), Ok("HI".to_string()));
+/// ```
+pub fn read(picture: &str) -> Result<String, OcrError> {
+    let mut rows: Vec<Vec<bool>> = picture
+        .lines()
+        .map(|line| line.trim_end_matches('\r').chars().map(lit).collect())
+        .collect();
+    let Some(first) = rows.iter().position(|row| row.contains(&true)) else {
+        return Err(OcrError::Height(0));
+    };
+    let last = rows.iter().rposition(|row| row.contains(&true)).unwrap();
+    rows.truncate(last + 1);
+    rows.drain(..first);
+
+    let font: &[(char, &str)] = match rows.len() {
+        6 => &SMALL,
+        10 => &LARGE,
+        height => return Err(OcrError::Height(height)),
+    };
+
+    let width = rows.iter().map(|row| row.len()).max().unwrap_or(0);
+    let pixel = |row: &Vec<bool>, col: usize| row.get(col).copied().unwrap_or(false);
+    let column_lit = |col: usize| rows.iter().any(|row| pixel(row, col));
+
+    let mut read = String::new();
+    let mut glyphs = Vec::new();
+    let mut col = 0;
+    while col < width {
+        if !column_lit(col) {
+            col += 1;
+            continue;
+        }
+        let start = col;
+        while col < width && column_lit(col) {
+            col += 1;
+        }
+
+        let picture = rows
+            .iter()
+            .map(|row| {
+                (start..col)
+                    .map(|c| if pixel(row, c) { '#' } else { '.' })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        match font.iter().find(|(_, glyph)| *glyph == picture) {
+            Some((letter, _)) => read.push(*letter),
+            None => {
+                read.push('?');
+                glyphs.push(Glyph {
+                    column: start + 1,
+                    picture,
+                });
+            }
+        }
+    }
+
+    if glyphs.is_empty() {
+        Ok(read)
+    } else {
+        Err(OcrError::Unrecognized { read, glyphs })
+    }
+}
+
+/// Reads the letters drawn by the `#` tiles of a [`crate::grid::Grid`] or
+/// [`crate::grid::DenseGrid`].
+pub fn read_grid<C>(grid: &impl Tiles<char, C>) -> Result<String, OcrError>
+where
+    C: Coordinate + Hash + Eq,
+    C::Unit: Step + Display,
+{
+    read(&grid.render(|_, t| t.copied().unwrap_or('.').to_string()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::{read, read_grid, Glyph, OcrError, LARGE, SMALL};
+    use crate::{
+        coordinate::RowCol,
+        grid::{DenseGrid, Grid},
+    };
+
+    // Draws the glyphs side by side with `gap` dark columns between them
+    fn draw(font: &[(char, &str)], gap: usize) -> String {
+        let glyphs: Vec<Vec<&str>> = font.iter().map(|(_, g)| g.lines().collect()).collect();
+        (0..glyphs[0].len())
+            .map(|row| {
+                glyphs
+                    .iter()
+                    .map(|g| g[row])
+                    .collect::<Vec<_>>()
+                    .join(&".".repeat(gap))
+                    + "\n"
+            })
+            .collect()
+    }
+
+    #[test]
+    fn every_letter_reads_back() {
+        let letters = |font: &[(char, &str)]| font.iter().map(|(l, _)| *l).collect::<String>();
+        assert_eq!(read(&draw(&SMALL, 1)), Ok(letters(&SMALL)));
+        assert_eq!(read(&draw(&LARGE, 2)), Ok(letters(&LARGE)));
+
+        // Surrounding dark pixels and other lit characters don't matter
+        let framed = format!("\n  \n{}\n", draw(&SMALL[..3], 3).replace('#', "█"));
+        assert_eq!(read(&framed), Ok("ABC".to_string()));
+    }
+
+    #[test]
+    fn reads_a_grid() {
+        let picture = draw(&LARGE[10..], 2);
+        let grid = Grid::<char, RowCol>::parse(&picture, |_, ch| ch);
+        assert_eq!(read_grid(&grid), Ok("NPRXZ".to_string()));
+        let dense = DenseGrid::<char, RowCol>::parse(&picture, |_, ch| ch);
+        assert_eq!(read_grid(&dense), Ok("NPRXZ".to_string()));
+    }
+
+    #[test]
+    fn reports_unknown_glyphs() {
+        let picture = "#..#.#\n#..#.#\n####.#\n#..#.#\n#..#..\n#..#.#\n";
+        assert_eq!(
+            read(picture),
+            Err(OcrError::Unrecognized {
+                read: "H?".to_string(),
+                glyphs: vec![Glyph {
+                    column: 6,
+                    picture: "#\n#\n#\n#\n.\n#".to_string(),
+                }],
+            })
+        );
+        assert_eq!(read("#\n#\n#\n"), Err(OcrError::Height(3)));
+        assert_eq!(read("....\n"), Err(OcrError::Height(0)));
+    }
+}
@@ -0,0 +1,279 @@
+//! Branch and bound: finding the best of a tree of choices too large to walk,
+//! by skipping branches that can't beat the best found so far.
+
+use std::{cmp::Ordering, collections::BinaryHeap, collections::HashMap, hash::Hash};
+
+/// A puzzle to solve with [`maximize`]. Every state is a solution in its own
+/// right, worth [`Problem::value`], and the moves out of it lead to others.
+///
+/// Scores are maximized. To minimize a cost, score with
+/// [`std::cmp::Reverse`] of it.
+pub trait Problem {
+    type State;
+    type Move: Clone;
+    type Score: Ord + Copy;
+    /// What makes states comparable with [`Problem::dominates`].
+    type Key: Hash + Eq;
+
+    /// The moves that can be made from `state`, and where each one leads.
+    fn successors(&self, state: &Self::State) -> Vec<(Self::Move, Self::State)>;
+
+    /// The score of stopping at `state`.
+    fn value(&self, state: &Self::State) -> Self::Score;
+
+    /// A score that nothing reachable from `state` can beat, counting `state`
+    /// itself. The closer it is, the more is skipped, but if it is ever too
+    /// low the best solution may be missed.
+    fn bound(&self, state: &Self::State) -> Self::Score;
+
+    /// States with the same key are compared with [`Problem::dominates`].
+    /// States without one never are.
+    fn key(&self, _state: &Self::State) -> Option<Self::Key> {
+        None
+    }
+
+    /// Whether nothing reachable from `b` can beat the best reachable from
+    /// `a`, so that `b` needn't be explored.
+    fn dominates(&self, _a: &Self::State, _b: &Self::State) -> bool {
+        false
+    }
+}
+
+/// The best solution [`maximize`] found.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Best<S, M, Score> {
+    pub score: Score,
+    pub state: S,
+    /// The moves from the start to `state`.
+    pub path: Vec<M>,
+    /// How many states were expanded to find it.
+    pub explored: usize,
+}
+
+struct Node<S, M> {
+    state: S,
+    parent: Option<usize>,
+    step: Option<M>,
+    dominated: bool,
+}
+
+/// A node waiting to be expanded, ordered so that `BinaryHeap` pops the most
+/// promising first.
+struct Queued<Score> {
+    bound: Score,
+    value: Score,
+    node: usize,
+}
+
+impl<Score: Ord> PartialEq for Queued<Score> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<Score: Ord> Eq for Queued<Score> {}
+
+impl<Score: Ord> PartialOrd for Queued<Score> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<Score: Ord> Ord for Queued<Score> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.bound
+            .cmp(&other.bound)
+            .then_with(|| self.value.cmp(&other.value))
+    }
+}
+
+/// Finds the state reachable from `start` with the highest value, expanding
+/// states in order of their bound. The search ends once no state left to
+/// expand has a bound above the best value found.
+pub fn maximize<P: Problem>(problem: &P, start: P::State) -> Best<P::State, P::Move, P::Score> {
+    let mut nodes = Vec::new();
+    let mut groups: HashMap<P::Key, Vec<usize>> = HashMap::new();
+    let mut queue = BinaryHeap::new();
+    let mut best = (problem.value(&start), 0);
+    let mut explored = 0;
+
+    queue.push(Queued {
+        bound: problem.bound(&start),
+        value: best.0,
+        node: 0,
+    });
+    nodes.push(Node {
+        state: start,
+        parent: None,
+        step: None,
+        dominated: false,
+    });
+
+    while let Some(Queued { bound, node, .. }) = queue.pop() {
+        if bound <= best.0 {
+            break;
+        }
+        if nodes[node].dominated {
+            continue;
+        }
+        explored += 1;
+
+        for (step, state) in problem.successors(&nodes[node].state) {
+            let value = problem.value(&state);
+            let bound = problem.bound(&state);
+            if bound <= best.0 {
+                continue;
+            }
+
+            let index = nodes.len();
+            if let Some(key) = problem.key(&state) {
+                let group = groups.entry(key).or_default();
+                if group
+                    .iter()
+                    .any(|&other| problem.dominates(&nodes[other].state, &state))
+                {
+                    continue;
+                }
+                group.retain(|&other| {
+                    let dominated = problem.dominates(&state, &nodes[other].state);
+                    nodes[other].dominated |= dominated;
+                    !dominated
+                });
+                group.push(index);
+            }
+
+            nodes.push(Node {
+                state,
+                parent: Some(node),
+                step: Some(step),
+                dominated: false,
+            });
+            if value > best.0 {
+                best = (value, index);
+            }
+            if bound > best.0 {
+                queue.push(Queued {
+                    bound,
+                    value,
+                    node: index,
+                });
+            }
+        }
+    }
+
+    let mut path = Vec::new();
+    let mut node = best.1;
+    while let Some(parent) = nodes[node].parent {
+        path.push(nodes[node].step.clone().unwrap());
+        node = parent;
+    }
+    path.reverse();
+
+    Best {
+        score: best.0,
+        state: nodes.swap_remove(best.1).state,
+        path,
+        explored,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{maximize, Problem};
+
+    // Packing a knapsack, one item at a time
+    struct Knapsack {
+        items: Vec<(u32, u32)>,
+        capacity: u32,
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Packed {
+        next: usize,
+        weight: u32,
+        value: u32,
+    }
+
+    impl Problem for Knapsack {
+        type State = Packed;
+        type Move = Option<usize>;
+        type Score = u32;
+        type Key = usize;
+
+        fn successors(&self, state: &Packed) -> Vec<(Option<usize>, Packed)> {
+            let Some(&(weight, value)) = self.items.get(state.next) else {
+                return Vec::new();
+            };
+            let skip = Packed {
+                next: state.next + 1,
+                ..*state
+            };
+            let mut moves = vec![(None, skip)];
+            if state.weight + weight <= self.capacity {
+                let take = Packed {
+                    next: state.next + 1,
+                    weight: state.weight + weight,
+                    value: state.value + value,
+                };
+                moves.push((Some(state.next), take));
+            }
+            moves
+        }
+
+        fn value(&self, state: &Packed) -> u32 {
+            state.value
+        }
+
+        fn bound(&self, state: &Packed) -> u32 {
+            state.value + self.items[state.next..].iter().map(|(_, v)| v).sum::<u32>()
+        }
+
+        fn key(&self, state: &Packed) -> Option<usize> {
+            Some(state.next)
+        }
+
+        fn dominates(&self, a: &Packed, b: &Packed) -> bool {
+            a.weight <= b.weight && a.value >= b.value
+        }
+    }
+
+    // Every subset of the items that fits, for checking against
+    fn brute_force(knapsack: &Knapsack) -> u32 {
+        (0..1u32 << knapsack.items.len())
+            .filter_map(|set| {
+                let chosen = knapsack
+                    .items
+                    .iter()
+                    .enumerate()
+                    .filter(|(i, _)| set & (1 << i) != 0);
+                let (weight, value) = chosen.fold((0, 0), |(w, v), (_, (iw, iv))| (w + iw, v + iv));
+                (weight <= knapsack.capacity).then_some(value)
+            })
+            .max()
+            .unwrap()
+    }
+
+    #[test]
+    fn packs_the_best_knapsack() {
+        let knapsack = Knapsack {
+            items: vec![(12, 4), (2, 2), (1, 1), (1, 2), (4, 10), (3, 7), (7, 8)],
+            capacity: 15,
+        };
+        let start = Packed {
+            next: 0,
+            weight: 0,
+            value: 0,
+        };
+        let best = maximize(&knapsack, start);
+        assert_eq!(best.score, brute_force(&knapsack));
+        assert_eq!(best.score, 27);
+
+        let taken: Vec<usize> = best.path.iter().flatten().copied().collect();
+        let weight: u32 = taken.iter().map(|&i| knapsack.items[i].0).sum();
+        let value: u32 = taken.iter().map(|&i| knapsack.items[i].1).sum();
+        assert!(weight <= knapsack.capacity);
+        assert_eq!(value, best.score);
+        assert_eq!(best.state.value, best.score);
+        assert!(best.explored < 1 << knapsack.items.len());
+    }
+}
@@ -0,0 +1,267 @@
+//! [nom] combinators for the shapes puzzle inputs keep coming in.
+//!
+//! Parsers here use [`VerboseError`] so that [`parse_all`] can say what was
+//! expected, and where.
+
+use std::str::FromStr;
+
+use nom::{
+    bytes::complete::tag,
+    character::complete::{char, digit1, line_ending, multispace0, one_of, space0, space1},
+    combinator::{all_consuming, map_res, opt, recognize},
+    error::{VerboseError, VerboseErrorKind},
+    multi::{many0_count, many1_count},
+    sequence::{delimited, pair, preceded, separated_pair, terminated, tuple},
+    Parser,
+};
+
+use crate::{coordinate::Coordinate, error::ParseError};
+
+pub type IResult<'a, O> = nom::IResult<&'a str, O, VerboseError<&'a str>>;
+
+/// Skips spaces and tabs before `parser`.
+pub fn ws<'a, O>(
+    parser: impl Parser<&'a str, O, VerboseError<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<'a, O> {
+    preceded(space0, parser)
+}
+
+/// A decimal integer with an optional sign, of whatever type is asked for.
+/// Values that don't fit the type are an error rather than wrapping.
+pub fn integer<T: FromStr>(i: &str) -> IResult<'_, T> {
+    map_res(recognize(pair(opt(one_of("+-")), digit1)), str::parse)(i)
+}
+
+// Like nom's `separated_list1`, except that a separator followed by more of
+// the line than spaces must be followed by an item. A bad item is then
+// reported where it went wrong, instead of as leftover input after the last
+// good one.
+fn separated<'a, O, S>(
+    mut separator: impl Parser<&'a str, S, VerboseError<&'a str>>,
+    mut item: impl Parser<&'a str, O, VerboseError<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<'a, Vec<O>> {
+    move |i| {
+        let (mut i, first) = item.parse(i)?;
+        let mut items = vec![first];
+        loop {
+            let Ok((next, _)) = separator.parse(i) else {
+                return Ok((i, items));
+            };
+            let rest_of_line = next.trim_start_matches([' ', '\t']);
+            if rest_of_line.is_empty() || rest_of_line.starts_with(['\n', '\r']) {
+                return Ok((i, items));
+            }
+            let (next, o) = item.parse(next)?;
+            items.push(o);
+            i = next;
+        }
+    }
+}
+
+/// One or more `item`s separated by commas, with any spaces around them.
+pub fn list<'a, O>(
+    item: impl Parser<&'a str, O, VerboseError<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<'a, Vec<O>> {
+    separated(delimited(space0, char(','), space0), item)
+}
+
+/// One or more `item`s separated by spaces or tabs.
+pub fn words<'a, O>(
+    item: impl Parser<&'a str, O, VerboseError<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<'a, Vec<O>> {
+    separated(space1, item)
+}
+
+/// The value of `key=value` for the given key, as in `rate=13`.
+pub fn key_value<'a, O>(
+    key: &'a str,
+    value: impl Parser<&'a str, O, VerboseError<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<'a, O> {
+    preceded(tuple((tag(key), space0, char('='), space0)), value)
+}
+
+/// A coordinate written as `x=2, y=18`.
+pub fn coordinate<C>(i: &str) -> IResult<'_, C>
+where
+    C: Coordinate,
+    C::Unit: FromStr,
+{
+    let (i, (x, y)) = separated_pair(
+        key_value("x", integer),
+        delimited(space0, char(','), space0),
+        key_value("y", integer),
+    )(i)?;
+    Ok((i, C::from_horz_vert(x, y)))
+}
+
+/// One or more `item`s, one per line, up to a blank line or the end of the
+/// input.
+pub fn lines<'a, O>(
+    item: impl Parser<&'a str, O, VerboseError<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<'a, Vec<O>> {
+    separated(line_ending, item)
+}
+
+// A line that is empty or holds only spaces and tabs, with its line ending
+fn blank_line(i: &str) -> IResult<'_, ()> {
+    let (i, _) = pair(space0, line_ending)(i)?;
+    Ok((i, ()))
+}
+
+/// One or more `block`s separated by blank lines, by the same rule as
+/// [`crate::input::blocks`]: there may be several blank lines, lines of only
+/// whitespace count as blank, and blank lines at the start are skipped. A
+/// block usually parses [`lines`] of something.
+pub fn blocks<'a, O>(
+    block: impl Parser<&'a str, O, VerboseError<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<'a, Vec<O>> {
+    preceded(
+        many0_count(blank_line),
+        separated(pair(line_ending, many1_count(blank_line)), block),
+    )
+}
+
+/// Runs `parser` over the whole of `input`, allowing only trailing
+/// whitespace to be left over. A failure points at the line and column
+/// where parsing went wrong.
+pub fn parse_all<'a, O>(
+    parser: impl Parser<&'a str, O, VerboseError<&'a str>>,
+    input: &'a str,
+) -> Result<O, ParseError> {
+    match all_consuming(terminated(parser, multispace0))(input) {
+        Ok((_, output)) => Ok(output),
+        Err(nom::Err::Error(e) | nom::Err::Failure(e)) => Err(located_error(input, &e)),
+        Err(nom::Err::Incomplete(_)) => Err(error_at(
+            input,
+            input.len(),
+            "unexpected end of input".to_string(),
+        )),
+    }
+}
+
+// The first error is the innermost, which says what was expected. The
+// contexts wrapped around it say what was being parsed.
+fn located_error(input: &str, e: &VerboseError<&str>) -> ParseError {
+    let Some((remaining, kind)) = e.errors.first() else {
+        return error_at(input, 0, "invalid input".to_string());
+    };
+    let mut message = match kind {
+        VerboseErrorKind::Char(c) => format!("expected '{}'", c),
+        VerboseErrorKind::Context(context) => format!("expected {}", context),
+        VerboseErrorKind::Nom(nom::error::ErrorKind::Eof) => "unexpected input".to_string(),
+        VerboseErrorKind::Nom(kind) => format!("expected {}", kind.description()),
+    };
+    for (_, kind) in &e.errors[1..] {
+        if let VerboseErrorKind::Context(context) = kind {
+            message.push_str(&format!(" in {}", context));
+        }
+    }
+    error_at(input, input.len() - remaining.len(), message)
+}
+
+fn error_at(input: &str, offset: usize, message: String) -> ParseError {
+    let line_start = input[..offset].rfind('\n').map_or(0, |n| n + 1);
+    let line_end = input[offset..]
+        .find('\n')
+        .map_or(input.len(), |n| offset + n);
+    ParseError {
+        file: None,
+        line: input[..offset].matches('\n').count() + 1,
+        column: Some(input[line_start..offset].chars().count() + 1),
+        text: input[line_start..line_end]
+            .trim_end_matches('\r')
+            .to_string(),
+        message,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use nom::{character::complete::alpha1, error::context, sequence::preceded};
+
+    use super::{
+        blocks, coordinate, integer, key_value, lines, list, parse_all, words, ws, IResult,
+    };
+    use crate::coordinate::{Coordinate, RowCol, XY};
+
+    #[test]
+    fn integers() {
+        assert_eq!(parse_all(integer::<i64>, "-42\n"), Ok(-42));
+        assert_eq!(parse_all(integer::<u8>, "+255"), Ok(255));
+        assert!(parse_all(integer::<u8>, "256").is_err());
+        assert!(parse_all(integer::<u32>, "-1").is_err());
+        assert_eq!(
+            parse_all(integer::<i128>, "170141183460469231731687303715884105727"),
+            Ok(i128::MAX)
+        );
+    }
+
+    #[test]
+    fn separated() {
+        assert_eq!(
+            parse_all(list(integer::<i32>), "1, 2,3 ,4"),
+            Ok(vec![1, 2, 3, 4])
+        );
+        assert_eq!(
+            parse_all(words(alpha1), "DD  II\tBB"),
+            Ok(vec!["DD", "II", "BB"])
+        );
+        assert_eq!(
+            parse_all(preceded(alpha1, ws(integer::<u8>)), "old   7"),
+            Ok(7)
+        );
+        assert_eq!(
+            parse_all(key_value("rate", integer::<u32>), "rate = 13"),
+            Ok(13)
+        );
+    }
+
+    #[test]
+    fn coordinates() {
+        let c: XY<i32> = parse_all(coordinate, "x=2, y=-18").unwrap();
+        assert_eq!((*c.x(), *c.y()), (2, -18));
+        let c: RowCol<usize> = parse_all(coordinate, "x=3,y=5").unwrap();
+        assert_eq!((*c.horizontal(), *c.vertical()), (3, 5));
+    }
+
+    #[test]
+    fn blocks_of_lines() {
+        let input = "1000\n2000\n\n4000\n\n5000\n6000\n";
+        assert_eq!(
+            parse_all(blocks(lines(integer::<u32>)), input),
+            Ok(vec![vec![1000, 2000], vec![4000], vec![5000, 6000]])
+        );
+
+        // Blank lines as input::blocks counts them
+        let input = "\n1000\r\n2000\n \n\t\n\n4000\n\n";
+        assert_eq!(
+            parse_all(blocks(lines(integer::<u32>)), input),
+            Ok(vec![vec![1000, 2000], vec![4000]])
+        );
+    }
+
+    fn valve(i: &str) -> IResult<'_, (&str, u32)> {
+        context(
+            "valve",
+            preceded(
+                nom::bytes::complete::tag("Valve "),
+                nom::sequence::pair(alpha1, ws(key_value("rate", integer))),
+            ),
+        )(i)
+    }
+
+    #[test]
+    fn errors_point_at_the_span() {
+        let e = parse_all(lines(valve), "Valve AA rate=0\nValve BB rate=x\n").unwrap_err();
+        assert_eq!((e.line, e.column), (2, Some(15)));
+        assert_eq!(e.text, "Valve BB rate=x");
+        assert_eq!(
+            e.to_string(),
+            "input:2:15: expected Digit in valve\n    Valve BB rate=x\n                  ^"
+        );
+
+        let e = parse_all(list(integer::<i32>), "1, 2,\n3").unwrap_err();
+        assert_eq!((e.line, e.column), (1, Some(5)));
+        assert_eq!(e.message, "unexpected input");
+    }
+}
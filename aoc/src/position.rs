@@ -63,6 +63,103 @@ impl Direction {
     }
 }
 
+/// The eight directions to neighboring tiles, clockwise from `Up`.
+pub const EACH_DIRECTION8: [Direction8; 8] = [
+    Direction8::Up,
+    Direction8::UpRight,
+    Direction8::Right,
+    Direction8::DownRight,
+    Direction8::Down,
+    Direction8::DownLeft,
+    Direction8::Left,
+    Direction8::UpLeft,
+];
+
+/// A [`Direction`] that can also point diagonally.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum Direction8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+    None,
+}
+
+impl Direction8 {
+    /// Combines a vertical (`Up`, `Down` or `None`) and a horizontal (`Left`,
+    /// `Right` or `None`) direction.
+    pub fn from_components(vertical: Direction, horizontal: Direction) -> Self {
+        match (vertical, horizontal) {
+            (Direction::Up, Direction::None) => Direction8::Up,
+            (Direction::Up, Direction::Right) => Direction8::UpRight,
+            (Direction::None, Direction::Right) => Direction8::Right,
+            (Direction::Down, Direction::Right) => Direction8::DownRight,
+            (Direction::Down, Direction::None) => Direction8::Down,
+            (Direction::Down, Direction::Left) => Direction8::DownLeft,
+            (Direction::None, Direction::Left) => Direction8::Left,
+            (Direction::Up, Direction::Left) => Direction8::UpLeft,
+            (Direction::None, Direction::None) => Direction8::None,
+            _ => panic!(
+                "{:?} and {:?} are not a vertical and a horizontal direction",
+                vertical, horizontal
+            ),
+        }
+    }
+
+    /// Splits the direction into its vertical and horizontal parts.
+    pub fn components(&self) -> (Direction, Direction) {
+        match self {
+            Direction8::Up => (Direction::Up, Direction::None),
+            Direction8::UpRight => (Direction::Up, Direction::Right),
+            Direction8::Right => (Direction::None, Direction::Right),
+            Direction8::DownRight => (Direction::Down, Direction::Right),
+            Direction8::Down => (Direction::Down, Direction::None),
+            Direction8::DownLeft => (Direction::Down, Direction::Left),
+            Direction8::Left => (Direction::None, Direction::Left),
+            Direction8::UpLeft => (Direction::Up, Direction::Left),
+            Direction8::None => (Direction::None, Direction::None),
+        }
+    }
+
+    pub fn is_diagonal(&self) -> bool {
+        matches!(
+            self,
+            Direction8::UpRight | Direction8::DownRight | Direction8::DownLeft | Direction8::UpLeft
+        )
+    }
+
+    pub fn opposite(&self) -> Self {
+        let (vertical, horizontal) = self.components();
+        Self::from_components(vertical.opposite(), horizontal.opposite())
+    }
+
+    /// Turns an eighth of a full turn in the direction of `rotation`.
+    pub fn rotate(&self, rotation: Rotation) -> Direction8 {
+        match EACH_DIRECTION8.iter().position(|d| d == self) {
+            Some(i) => match rotation {
+                Rotation::Left => EACH_DIRECTION8[(i + 7) % 8],
+                Rotation::Right => EACH_DIRECTION8[(i + 1) % 8],
+            },
+            None => Direction8::None,
+        }
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(value: Direction) -> Self {
+        match value {
+            Direction::Up | Direction::Down => Self::from_components(value, Direction::None),
+            Direction::Left | Direction::Right | Direction::None => {
+                Self::from_components(Direction::None, value)
+            }
+        }
+    }
+}
+
 pub const EACH_MOVEMENT: [Movement; 4] = [
     Movement::Forward,
     Movement::Back,
@@ -183,3 +280,35 @@ impl Position {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::{Direction, Direction8, Rotation, EACH_DIRECTION8};
+
+    #[test]
+    fn direction8_rotate() {
+        assert_eq!(Direction8::Up.rotate(Rotation::Right), Direction8::UpRight);
+        assert_eq!(Direction8::Up.rotate(Rotation::Left), Direction8::UpLeft);
+        assert_eq!(Direction8::None.rotate(Rotation::Left), Direction8::None);
+        for d in EACH_DIRECTION8 {
+            assert_eq!(d.rotate(Rotation::Left).rotate(Rotation::Right), d);
+            let half_turn = (0..4).fold(d, |d, _| d.rotate(Rotation::Right));
+            assert_eq!(half_turn, d.opposite());
+        }
+    }
+
+    #[test]
+    fn direction8_components() {
+        assert_eq!(Direction8::DownLeft.opposite(), Direction8::UpRight);
+        assert_eq!(Direction8::from(Direction::Left), Direction8::Left);
+        assert!(!Direction8::from(Direction::Down).is_diagonal());
+        for d in EACH_DIRECTION8 {
+            let (vertical, horizontal) = d.components();
+            assert_eq!(Direction8::from_components(vertical, horizontal), d);
+            assert_eq!(
+                d.is_diagonal(),
+                vertical != Direction::None && horizontal != Direction::None
+            );
+        }
+    }
+}
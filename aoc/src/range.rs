@@ -1,7 +1,21 @@
-use std::{
-    iter::Step,
-    ops::{RangeInclusive, Sub},
-};
+use std::{iter::Step, ops::RangeInclusive};
+
+// `std::cmp::{min, max}` require `Ord`, but ranges only need `PartialOrd`
+fn min<'a, T: PartialOrd>(a: &'a T, b: &'a T) -> &'a T {
+    if b < a {
+        b
+    } else {
+        a
+    }
+}
+
+fn max<'a, T: PartialOrd>(a: &'a T, b: &'a T) -> &'a T {
+    if b > a {
+        b
+    } else {
+        a
+    }
+}
 
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct Range<T> {
@@ -9,8 +23,14 @@ pub struct Range<T> {
 }
 
 impl<T: Clone + PartialOrd> Range<T> {
+    /// A range of the points from `range.start()` to `range.end()`, which is
+    /// empty if the end comes before the start.
     pub fn new(range: RangeInclusive<T>) -> Self {
-        Self { range: Some(range) }
+        if range.start() <= range.end() {
+            Self { range: Some(range) }
+        } else {
+            Self::empty()
+        }
     }
     pub fn empty() -> Self {
         Self { range: None }
@@ -64,104 +84,107 @@ impl<T: Clone + PartialOrd> Range<T> {
     pub fn end(&self) -> Option<&T> {
         self.range.as_ref().map(|r| r.end())
     }
+    /// The number of points in the range.
     pub fn count(&self) -> usize
     where
-        T: Sub + Copy,
-        usize: From<<T as Sub>::Output>,
+        T: Step,
     {
         if let Some(r) = &self.range {
-            <<T as std::ops::Sub>::Output as Into<usize>>::into(*r.end() - *r.start()) + 1
+            T::steps_between(r.start(), r.end()).1.unwrap() + 1
         } else {
             0
         }
     }
 
+    /// Whether the two ranges share at least one point.
+    pub fn overlaps(&self, other: &Self) -> bool {
+        !self.intersect(other).is_empty()
+    }
+
+    /// Whether the two ranges do not overlap, but one ends on the point just
+    /// before the other starts.
+    pub fn is_adjacent(&self, other: &Self) -> bool
+    where
+        T: Step,
+    {
+        match (&self.range, &other.range) {
+            (Some(s), Some(o)) => {
+                Step::forward_checked(s.end().clone(), 1).as_ref() == Some(o.start())
+                    || Step::forward_checked(o.end().clone(), 1).as_ref() == Some(s.start())
+            }
+            _ => false,
+        }
+    }
+
+    /// The points in either range, as a single range if they overlap or are
+    /// adjacent, or otherwise as two ranges in ascending order.
     pub fn union(&self, other: &Self) -> Vec<Self>
     where
         T: Step,
     {
         match (&self.range, &other.range) {
             (None, None) => vec![],
-            (None, Some(_o)) => vec![other.clone()],
-            (Some(_s), None) => vec![self.clone()],
-            (Some(_s), Some(_o)) => {
-                let self_start = self.start().unwrap().clone();
-                let self_end = self.end().unwrap().clone();
-                let other_start = other.start().unwrap().clone();
-                let other_end = other.end().unwrap().clone();
-
-                if self_start < other_start {
-                    if self_end < other_start {
-                        if Step::forward(self_end, 1) == other_start {
-                            vec![Self {
-                                range: Some(self_start..=other_end),
-                            }]
-                        } else {
-                            vec![self.clone(), other.clone()]
-                        }
-                    } else if self_end < other_end {
-                        vec![Self {
-                            range: Some(self_start..=other_end),
-                        }]
-                    } else {
-                        vec![Self {
-                            range: Some(self_start..=self_end),
-                        }]
-                    }
-                } else if self_start < other_end {
-                    if self_end < other_end {
-                        vec![Self {
-                            range: Some(other_start..=other_end),
-                        }]
-                    } else {
-                        vec![Self {
-                            range: Some(other_start..=self_end),
-                        }]
-                    }
-                } else {
+            (None, Some(_)) => vec![other.clone()],
+            (Some(_), None) => vec![self.clone()],
+            (Some(s), Some(o)) => {
+                if self.overlaps(other) || self.is_adjacent(other) {
+                    let start = min(s.start(), o.start()).clone();
+                    let end = max(s.end(), o.end()).clone();
+                    vec![Self::new(start..=end)]
+                } else if s.start() < o.start() {
                     vec![self.clone(), other.clone()]
+                } else {
+                    vec![other.clone(), self.clone()]
                 }
             }
         }
     }
 
     pub fn intersect(&self, other: &Self) -> Self {
-        if self.is_empty() || other.is_empty() {
-            Self::empty()
-        } else {
-            let self_start = self.start().unwrap().clone();
-            let self_end = self.end().unwrap().clone();
-            let other_start = other.start().unwrap().clone();
-            let other_end = other.end().unwrap().clone();
-
-            if self_start < other_start {
-                if self_end < other_start {
-                    Self { range: None }
-                } else if self_end < other_end {
-                    Self {
-                        range: Some(other_start..=self_end),
-                    }
-                } else {
-                    Self {
-                        range: Some(other_start..=other_end),
-                    }
-                }
-            } else if self_start < other_end {
-                if self_end < other_end {
-                    Self {
-                        range: Some(self_start..=self_end),
-                    }
+        match (&self.range, &other.range) {
+            (Some(s), Some(o)) => {
+                let start = max(s.start(), o.start());
+                let end = min(s.end(), o.end());
+                if start <= end {
+                    Self::new(start.clone()..=end.clone())
                 } else {
-                    Self {
-                        range: Some(self_start..=other_end),
-                    }
+                    Self::empty()
                 }
-            } else {
-                Self { range: None }
             }
+            _ => Self::empty(),
         }
     }
 
+    /// The points of this range that are not in `other`: nothing, a single
+    /// range, or (when `other` falls strictly inside) the two pieces either
+    /// side of it in ascending order.
+    pub fn difference(&self, other: &Self) -> Vec<Self>
+    where
+        T: Step,
+    {
+        let (s, o) = match (&self.range, &other.range) {
+            (None, _) => return vec![],
+            (Some(_), None) => return vec![self.clone()],
+            (Some(s), Some(o)) => (s, o),
+        };
+        if !self.overlaps(other) {
+            return vec![self.clone()];
+        }
+
+        let mut pieces = Vec::new();
+        if s.start() < o.start() {
+            pieces.push(Self::new(
+                s.start().clone()..=Step::backward(o.start().clone(), 1),
+            ));
+        }
+        if o.end() < s.end() {
+            pieces.push(Self::new(
+                Step::forward(o.end().clone(), 1)..=s.end().clone(),
+            ));
+        }
+        pieces
+    }
+
     pub fn is_empty(&self) -> bool {
         self.range.is_none()
     }
@@ -169,6 +192,10 @@ impl<T: Clone + PartialOrd> Range<T> {
 
 #[cfg(test)]
 mod test {
+    use std::collections::BTreeSet;
+
+    use proptest::prelude::*;
+
     use super::Range;
 
     #[test]
@@ -279,4 +306,98 @@ mod test {
             [Range::new(-2..=2)]
         );
     }
+
+    #[test]
+    fn difference() {
+        assert_eq!(
+            Range::new(0..=5).difference(&Range::new(2..=3)),
+            [Range::new(0..=1), Range::new(4..=5)]
+        );
+        assert_eq!(
+            Range::new(0..=5).difference(&Range::new(-1..=3)),
+            [Range::new(4..=5)]
+        );
+        assert_eq!(
+            Range::new(0..=5).difference(&Range::new(0..=5)),
+            Vec::<Range<i32>>::new()
+        );
+        assert_eq!(
+            Range::new(0..=5).difference(&Range::empty()),
+            [Range::new(0..=5)]
+        );
+    }
+
+    fn points(range: &Range<i32>) -> BTreeSet<i32> {
+        range.iter().collect()
+    }
+
+    fn pieces_points(pieces: &[Range<i32>]) -> BTreeSet<i32> {
+        pieces.iter().flat_map(points).collect()
+    }
+
+    // Pieces returned by union and difference must be non-empty, in ascending
+    // order and separated by at least one point
+    fn assert_separated(pieces: &[Range<i32>]) {
+        assert!(pieces.iter().all(|p| !p.is_empty()));
+        for pair in pieces.windows(2) {
+            assert!(*pair[0].end().unwrap() + 1 < *pair[1].start().unwrap());
+        }
+    }
+
+    fn any_range() -> impl Strategy<Value = Range<i32>> {
+        prop_oneof![
+            Just(Range::empty()),
+            (-20..20, 0..8).prop_map(|(start, len)| Range::new(start..=start + len)),
+            // Includes reversed bounds, which make an empty range
+            (-20..20, -20..20).prop_map(|(start, end)| Range::new(start..=end)),
+        ]
+    }
+
+    proptest! {
+        #[test]
+        fn union_matches_points(a in any_range(), b in any_range()) {
+            let union = a.union(&b);
+            assert_separated(&union);
+            prop_assert_eq!(pieces_points(&union), &points(&a) | &points(&b));
+            prop_assert_eq!(union, b.union(&a));
+        }
+
+        #[test]
+        fn intersect_matches_points(a in any_range(), b in any_range()) {
+            let intersection = a.intersect(&b);
+            prop_assert_eq!(points(&intersection), &points(&a) & &points(&b));
+            prop_assert_eq!(intersection.is_empty(), !a.overlaps(&b));
+            prop_assert_eq!(intersection, b.intersect(&a));
+        }
+
+        #[test]
+        fn difference_matches_points(a in any_range(), b in any_range()) {
+            let difference = a.difference(&b);
+            assert_separated(&difference);
+            prop_assert!(difference.len() <= 2);
+            prop_assert_eq!(pieces_points(&difference), &points(&a) - &points(&b));
+        }
+
+        #[test]
+        fn contains_and_count_match_points(a in any_range()) {
+            let a_points = points(&a);
+            prop_assert_eq!(a.count(), a_points.len());
+            prop_assert_eq!(a.is_empty(), a_points.is_empty());
+            for p in -30..30 {
+                prop_assert_eq!(a.contains(&p), a_points.contains(&p));
+            }
+        }
+
+        #[test]
+        fn adjacency_matches_points(a in any_range(), b in any_range()) {
+            let (a_points, b_points) = (points(&a), points(&b));
+            let touching = match (a_points.last(), b_points.first(), b_points.last(), a_points.first()) {
+                (Some(a_end), Some(b_start), Some(b_end), Some(a_start)) => {
+                    a_end + 1 == *b_start || b_end + 1 == *a_start
+                }
+                _ => false,
+            };
+            prop_assert_eq!(a.is_adjacent(&b), touching && a_points.is_disjoint(&b_points));
+        }
+    }
 }
@@ -0,0 +1,299 @@
+use std::iter::Step;
+
+use crate::range::Range;
+
+/// A set of points stored as sorted, disjoint and non-adjacent [`Range`]s, so
+/// that large spans can be covered without enumerating every point.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RangeSet<T> {
+    ranges: Vec<Range<T>>,
+}
+
+impl<T> Default for RangeSet<T>
+where
+    T: Step + Copy + Ord,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> RangeSet<T>
+where
+    T: Step + Copy + Ord,
+{
+    pub fn new() -> Self {
+        Self { ranges: Vec::new() }
+    }
+
+    /// The coalesced ranges in the set, in ascending order.
+    pub fn ranges(&self) -> &[Range<T>] {
+        &self.ranges
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Range<T>> {
+        self.ranges.iter()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// The total number of points covered by the set.
+    pub fn len(&self) -> usize {
+        self.ranges
+            .iter()
+            .map(|r| {
+                let (start, end) = bounds(r);
+                T::steps_between(&start, &end).1.unwrap() + 1
+            })
+            .sum()
+    }
+
+    pub fn contains(&self, point: &T) -> bool {
+        let i = self.ranges.partition_point(|r| bounds(r).1 < *point);
+        match self.ranges.get(i) {
+            Some(r) => bounds(r).0 <= *point,
+            None => false,
+        }
+    }
+
+    /// The lowest and highest points in the set.
+    pub fn span(&self) -> Range<T> {
+        match (self.ranges.first(), self.ranges.last()) {
+            (Some(first), Some(last)) => Range::new(bounds(first).0..=bounds(last).1),
+            _ => Range::empty(),
+        }
+    }
+
+    pub fn insert(&mut self, range: &Range<T>) {
+        if range.is_empty() {
+            return;
+        }
+        let (mut start, mut end) = bounds(range);
+
+        // Everything from `first` to `last` overlaps or touches the new range
+        let first = self.ranges.partition_point(|r| {
+            let (_, e) = bounds(r);
+            e < start && Step::forward(e, 1) < start
+        });
+        let last = self.ranges.partition_point(|r| {
+            let (s, _) = bounds(r);
+            s <= end || Step::backward(s, 1) <= end
+        });
+
+        if first < last {
+            start = start.min(bounds(&self.ranges[first]).0);
+            end = end.max(bounds(&self.ranges[last - 1]).1);
+        }
+        self.ranges.splice(first..last, [Range::new(start..=end)]);
+    }
+
+    pub fn remove(&mut self, range: &Range<T>) {
+        if range.is_empty() {
+            return;
+        }
+        let (start, end) = bounds(range);
+
+        let first = self.ranges.partition_point(|r| bounds(r).1 < start);
+        let last = self.ranges.partition_point(|r| bounds(r).0 <= end);
+        if first >= last {
+            return;
+        }
+
+        let mut remaining = Vec::new();
+        let (first_start, _) = bounds(&self.ranges[first]);
+        if first_start < start {
+            remaining.push(Range::new(first_start..=Step::backward(start, 1)));
+        }
+        let (_, last_end) = bounds(&self.ranges[last - 1]);
+        if last_end > end {
+            remaining.push(Range::new(Step::forward(end, 1)..=last_end));
+        }
+        self.ranges.splice(first..last, remaining);
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut result = self.clone();
+        for r in &other.ranges {
+            result.insert(r);
+        }
+        result
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while i < self.ranges.len() && j < other.ranges.len() {
+            let (a_start, a_end) = bounds(&self.ranges[i]);
+            let (b_start, b_end) = bounds(&other.ranges[j]);
+            let (start, end) = (a_start.max(b_start), a_end.min(b_end));
+            if start <= end {
+                ranges.push(Range::new(start..=end));
+            }
+            if a_end < b_end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        Self { ranges }
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut result = self.clone();
+        for r in &other.ranges {
+            result.remove(r);
+        }
+        result
+    }
+
+    /// The points of `within` that are not in the set.
+    pub fn complement(&self, within: &Range<T>) -> Self {
+        Self::from(within.clone()).difference(self)
+    }
+
+    /// The holes between consecutive ranges of the set.
+    pub fn gaps(&self) -> impl Iterator<Item = Range<T>> + '_ {
+        self.ranges.windows(2).map(|pair| {
+            let (_, end) = bounds(&pair[0]);
+            let (start, _) = bounds(&pair[1]);
+            Range::new(Step::forward(end, 1)..=Step::backward(start, 1))
+        })
+    }
+}
+
+fn bounds<T: Copy + PartialOrd>(range: &Range<T>) -> (T, T) {
+    (*range.start().unwrap(), *range.end().unwrap())
+}
+
+impl<T> From<Range<T>> for RangeSet<T>
+where
+    T: Step + Copy + Ord,
+{
+    fn from(value: Range<T>) -> Self {
+        let mut set = Self::new();
+        set.insert(&value);
+        set
+    }
+}
+
+impl<T> FromIterator<Range<T>> for RangeSet<T>
+where
+    T: Step + Copy + Ord,
+{
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(iter: I) -> Self {
+        let mut set = Self::new();
+        for r in iter {
+            set.insert(&r);
+        }
+        set
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::BTreeSet;
+
+    use super::RangeSet;
+    use crate::range::Range;
+
+    fn set(ranges: &[(i32, i32)]) -> RangeSet<i32> {
+        ranges.iter().map(|(s, e)| Range::new(*s..=*e)).collect()
+    }
+
+    fn points(set: &RangeSet<i32>) -> BTreeSet<i32> {
+        set.iter().flat_map(|r| r.iter()).collect()
+    }
+
+    #[test]
+    fn insert_coalesces() {
+        let s = set(&[(0, 2), (5, 6), (3, 3), (10, 12), (-4, -2)]);
+        assert_eq!(s, set(&[(-4, -2), (0, 3), (5, 6), (10, 12)]));
+        assert_eq!(s.ranges().len(), 4);
+        assert_eq!(s.len(), 3 + 4 + 2 + 3);
+
+        let s = set(&[(0, 2), (4, 6), (8, 10), (3, 7)]);
+        assert_eq!(s.ranges(), [Range::new(0..=10)]);
+    }
+
+    #[test]
+    fn remove_splits() {
+        let mut s = set(&[(0, 10), (20, 30)]);
+        s.remove(&Range::new(5..=5));
+        s.remove(&Range::new(9..=22));
+        assert_eq!(s, set(&[(0, 4), (6, 8), (23, 30)]));
+        s.remove(&Range::new(-100..=100));
+        assert!(s.is_empty());
+    }
+
+    #[test]
+    fn queries() {
+        let s = set(&[(0, 3), (6, 6), (9, 12)]);
+        assert!(s.contains(&0));
+        assert!(s.contains(&6));
+        assert!(!s.contains(&7));
+        assert!(!s.contains(&13));
+        assert!(!s.contains(&-1));
+        assert_eq!(
+            s.gaps().collect::<Vec<_>>(),
+            [Range::new(4..=5), Range::new(7..=8)]
+        );
+        assert_eq!(s.span(), Range::new(0..=12));
+        assert_eq!(
+            s.complement(&Range::new(-2..=10)),
+            set(&[(-2, -1), (4, 5), (7, 8)])
+        );
+    }
+
+    // Compares every operation against the same operation on a plain set of
+    // points, over a deterministic pseudo-random sequence of ranges
+    #[test]
+    fn matches_point_sets() {
+        let mut seed = 12345u32;
+        let mut next = |bound: u32| {
+            seed = seed.wrapping_mul(1103515245).wrapping_add(12345);
+            ((seed >> 16) % bound) as i32
+        };
+
+        for _ in 0..200 {
+            let mut a = RangeSet::new();
+            let mut b = RangeSet::new();
+            let mut a_points = BTreeSet::new();
+            for _ in 0..6 {
+                let (start, len) = (next(40) - 20, next(6));
+                let r = Range::new(start..=start + len);
+                if next(3) == 0 {
+                    a.remove(&r);
+                    for p in r.iter() {
+                        a_points.remove(&p);
+                    }
+                } else {
+                    a.insert(&r);
+                    a_points.extend(r.iter());
+                }
+                let start = next(40) - 20;
+                b.insert(&Range::new(start..=start + next(4)));
+            }
+            let b_points = points(&b);
+
+            assert_eq!(points(&a), a_points);
+            assert_eq!(a.len(), a_points.len());
+            for w in a.ranges().windows(2) {
+                // Ranges must be sorted with at least one point between them
+                assert!(*w[0].end().unwrap() + 1 < *w[1].start().unwrap());
+            }
+            for p in -25..25 {
+                assert_eq!(a.contains(&p), a_points.contains(&p));
+            }
+            assert_eq!(points(&a.union(&b)), &a_points | &b_points);
+            assert_eq!(points(&a.intersection(&b)), &a_points & &b_points);
+            assert_eq!(points(&a.difference(&b)), &a_points - &b_points);
+            let within = Range::new(-10..=10);
+            assert_eq!(
+                points(&a.complement(&within)),
+                within.iter().filter(|p| !a_points.contains(p)).collect()
+            );
+        }
+    }
+}
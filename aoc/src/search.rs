@@ -0,0 +1,425 @@
+use std::{
+    cmp::Ordering,
+    collections::{BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+/// The outcome of a graph search: the cost of reaching every visited node and
+/// the node each one was reached from.
+#[derive(Debug, Clone)]
+pub struct SearchResult<N, Cost = usize> {
+    distances: HashMap<N, Cost>,
+    predecessors: HashMap<N, N>,
+}
+
+impl<N, Cost> SearchResult<N, Cost>
+where
+    N: Eq + Hash + Clone,
+{
+    fn new() -> Self {
+        Self {
+            distances: HashMap::new(),
+            predecessors: HashMap::new(),
+        }
+    }
+
+    pub fn distance(&self, node: &N) -> Option<&Cost> {
+        self.distances.get(node)
+    }
+
+    pub fn distances(&self) -> &HashMap<N, Cost> {
+        &self.distances
+    }
+
+    pub fn into_distances(self) -> HashMap<N, Cost> {
+        self.distances
+    }
+
+    pub fn predecessors(&self) -> &HashMap<N, N> {
+        &self.predecessors
+    }
+
+    /// The nodes visited on the way from a start node to `node`, including
+    /// both ends, or `None` if `node` was never reached.
+    pub fn path_to(&self, node: &N) -> Option<Vec<N>> {
+        if !self.distances.contains_key(node) {
+            return None;
+        }
+        let mut path = vec![node.clone()];
+        while let Some(previous) = self.predecessors.get(path.last().unwrap()) {
+            path.push(previous.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+/// Breadth-first search from `start` over unit-cost edges, visiting every
+/// reachable node.
+pub fn bfs<N, I, F>(start: N, successors: F) -> SearchResult<N>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = N>,
+    F: FnMut(&N) -> I,
+{
+    bfs_multi([start], successors)
+}
+
+/// Breadth-first search from several start nodes at once, each at distance 0.
+pub fn bfs_multi<N, S, I, F>(starts: S, successors: F) -> SearchResult<N>
+where
+    N: Eq + Hash + Clone,
+    S: IntoIterator<Item = N>,
+    I: IntoIterator<Item = N>,
+    F: FnMut(&N) -> I,
+{
+    bfs_core(starts, successors, |_| false).1
+}
+
+/// Breadth-first search that stops at the first (and so nearest) node matching
+/// `goal`, returning it along with the search so far.
+pub fn bfs_until<N, I, F, G>(start: N, successors: F, goal: G) -> Option<(N, SearchResult<N>)>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = N>,
+    F: FnMut(&N) -> I,
+    G: FnMut(&N) -> bool,
+{
+    bfs_multi_until([start], successors, goal)
+}
+
+pub fn bfs_multi_until<N, S, I, F, G>(
+    starts: S,
+    successors: F,
+    goal: G,
+) -> Option<(N, SearchResult<N>)>
+where
+    N: Eq + Hash + Clone,
+    S: IntoIterator<Item = N>,
+    I: IntoIterator<Item = N>,
+    F: FnMut(&N) -> I,
+    G: FnMut(&N) -> bool,
+{
+    let (found, result) = bfs_core(starts, successors, goal);
+    found.map(|n| (n, result))
+}
+
+fn bfs_core<N, S, I, F, G>(
+    starts: S,
+    mut successors: F,
+    mut goal: G,
+) -> (Option<N>, SearchResult<N>)
+where
+    N: Eq + Hash + Clone,
+    S: IntoIterator<Item = N>,
+    I: IntoIterator<Item = N>,
+    F: FnMut(&N) -> I,
+    G: FnMut(&N) -> bool,
+{
+    let mut result = SearchResult::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if !result.distances.contains_key(&start) {
+            result.distances.insert(start.clone(), 0);
+            queue.push_back(start);
+        }
+    }
+
+    while let Some(node) = queue.pop_front() {
+        if goal(&node) {
+            return (Some(node), result);
+        }
+        let distance = result.distances[&node] + 1;
+        for next in successors(&node) {
+            if !result.distances.contains_key(&next) {
+                result.distances.insert(next.clone(), distance);
+                result.predecessors.insert(next.clone(), node.clone());
+                queue.push_back(next);
+            }
+        }
+    }
+    (None, result)
+}
+
+/// Dijkstra's algorithm from `start`, visiting every reachable node. Edge
+/// costs must not be negative.
+pub fn dijkstra<N, Cost, I, F>(start: N, successors: F) -> SearchResult<N, Cost>
+where
+    N: Eq + Hash + Clone,
+    Cost: Copy + Ord + Default + Add<Output = Cost>,
+    I: IntoIterator<Item = (N, Cost)>,
+    F: FnMut(&N) -> I,
+{
+    dijkstra_multi([start], successors)
+}
+
+pub fn dijkstra_multi<N, Cost, S, I, F>(starts: S, successors: F) -> SearchResult<N, Cost>
+where
+    N: Eq + Hash + Clone,
+    Cost: Copy + Ord + Default + Add<Output = Cost>,
+    S: IntoIterator<Item = N>,
+    I: IntoIterator<Item = (N, Cost)>,
+    F: FnMut(&N) -> I,
+{
+    best_first(starts, successors, |_| Cost::default(), |_| false).1
+}
+
+/// Dijkstra's algorithm that stops at the cheapest node matching `goal`.
+pub fn dijkstra_until<N, Cost, I, F, G>(
+    start: N,
+    successors: F,
+    goal: G,
+) -> Option<(N, SearchResult<N, Cost>)>
+where
+    N: Eq + Hash + Clone,
+    Cost: Copy + Ord + Default + Add<Output = Cost>,
+    I: IntoIterator<Item = (N, Cost)>,
+    F: FnMut(&N) -> I,
+    G: FnMut(&N) -> bool,
+{
+    dijkstra_multi_until([start], successors, goal)
+}
+
+pub fn dijkstra_multi_until<N, Cost, S, I, F, G>(
+    starts: S,
+    successors: F,
+    goal: G,
+) -> Option<(N, SearchResult<N, Cost>)>
+where
+    N: Eq + Hash + Clone,
+    Cost: Copy + Ord + Default + Add<Output = Cost>,
+    S: IntoIterator<Item = N>,
+    I: IntoIterator<Item = (N, Cost)>,
+    F: FnMut(&N) -> I,
+    G: FnMut(&N) -> bool,
+{
+    let (found, result) = best_first(starts, successors, |_| Cost::default(), goal);
+    found.map(|n| (n, result))
+}
+
+/// A* search from `start` to the nearest node matching `goal`. The
+/// `heuristic` must never overestimate the remaining cost, or the path found
+/// may not be the cheapest.
+pub fn astar<N, Cost, I, F, H, G>(
+    start: N,
+    successors: F,
+    heuristic: H,
+    goal: G,
+) -> Option<(N, SearchResult<N, Cost>)>
+where
+    N: Eq + Hash + Clone,
+    Cost: Copy + Ord + Default + Add<Output = Cost>,
+    I: IntoIterator<Item = (N, Cost)>,
+    F: FnMut(&N) -> I,
+    H: FnMut(&N) -> Cost,
+    G: FnMut(&N) -> bool,
+{
+    astar_multi([start], successors, heuristic, goal)
+}
+
+pub fn astar_multi<N, Cost, S, I, F, H, G>(
+    starts: S,
+    successors: F,
+    heuristic: H,
+    goal: G,
+) -> Option<(N, SearchResult<N, Cost>)>
+where
+    N: Eq + Hash + Clone,
+    Cost: Copy + Ord + Default + Add<Output = Cost>,
+    S: IntoIterator<Item = N>,
+    I: IntoIterator<Item = (N, Cost)>,
+    F: FnMut(&N) -> I,
+    H: FnMut(&N) -> Cost,
+    G: FnMut(&N) -> bool,
+{
+    let (found, result) = best_first(starts, successors, heuristic, goal);
+    found.map(|n| (n, result))
+}
+
+/// An entry in the open set, ordered so that `BinaryHeap` pops the lowest
+/// estimated total cost first.
+struct Queued<N, Cost> {
+    estimate: Cost,
+    cost: Cost,
+    node: N,
+}
+
+impl<N, Cost: Ord> PartialEq for Queued<N, Cost> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<N, Cost: Ord> Eq for Queued<N, Cost> {}
+
+impl<N, Cost: Ord> PartialOrd for Queued<N, Cost> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N, Cost: Ord> Ord for Queued<N, Cost> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .estimate
+            .cmp(&self.estimate)
+            .then_with(|| other.cost.cmp(&self.cost))
+    }
+}
+
+fn best_first<N, Cost, S, I, F, H, G>(
+    starts: S,
+    mut successors: F,
+    mut heuristic: H,
+    mut goal: G,
+) -> (Option<N>, SearchResult<N, Cost>)
+where
+    N: Eq + Hash + Clone,
+    Cost: Copy + Ord + Default + Add<Output = Cost>,
+    S: IntoIterator<Item = N>,
+    I: IntoIterator<Item = (N, Cost)>,
+    F: FnMut(&N) -> I,
+    H: FnMut(&N) -> Cost,
+    G: FnMut(&N) -> bool,
+{
+    let mut result = SearchResult::new();
+    let mut queue = BinaryHeap::new();
+    for start in starts {
+        result.distances.insert(start.clone(), Cost::default());
+        queue.push(Queued {
+            estimate: heuristic(&start),
+            cost: Cost::default(),
+            node: start,
+        });
+    }
+
+    while let Some(Queued { cost, node, .. }) = queue.pop() {
+        // Skip stale entries for nodes that were since reached more cheaply
+        if result.distances[&node] < cost {
+            continue;
+        }
+        if goal(&node) {
+            return (Some(node), result);
+        }
+        for (next, step) in successors(&node) {
+            let next_cost = cost + step;
+            let improved = match result.distances.get(&next) {
+                Some(existing) => next_cost < *existing,
+                None => true,
+            };
+            if improved {
+                result.distances.insert(next.clone(), next_cost);
+                result.predecessors.insert(next.clone(), node.clone());
+                queue.push(Queued {
+                    estimate: next_cost + heuristic(&next),
+                    cost: next_cost,
+                    node: next,
+                });
+            }
+        }
+    }
+    (None, result)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{astar, bfs, bfs_multi, bfs_until, dijkstra, dijkstra_until};
+    use crate::coordinate::{Coordinate, RowCol};
+    use crate::grid::{DenseGrid, Tiles};
+
+    const MAZE: &str = "\
+S..#....
+.#.#.##.
+.#...#..
+.####.#.
+......#E";
+
+    #[test]
+    fn bfs_on_grid() {
+        let (grid, markers) =
+            DenseGrid::<char, RowCol>::parse_with_markers(MAZE, &['S', 'E'], |_, ch| ch);
+        let (start, end) = (markers[&'S'][0], markers[&'E'][0]);
+
+        let result = bfs(start, |p| grid.passable_neighbors(p, |_, to| *to != '#'));
+        assert_eq!(result.distance(&end), Some(&15));
+        assert_eq!(result.distance(&RowCol::new(4, 5)), Some(&9));
+        assert_eq!(result.distance(&RowCol::new(0, 3)), None);
+
+        let path = result.path_to(&end).unwrap();
+        assert_eq!(path.len(), 16);
+        assert_eq!(path.first(), Some(&start));
+        assert_eq!(path.last(), Some(&end));
+        for step in path.windows(2) {
+            assert_eq!(step[0].manhatten_distance(&step[1]), 1);
+        }
+
+        let (found, _) = bfs_until(
+            start,
+            |p| grid.passable_neighbors(p, |_, to| *to != '#'),
+            |p| *p.col() == 4,
+        )
+        .unwrap();
+        assert_eq!(found, RowCol::new(2, 4));
+    }
+
+    #[test]
+    fn bfs_from_many_starts() {
+        let result = bfs_multi([0, 10], |n: &i32| {
+            [n - 1, n + 1].into_iter().filter(|n| (0..=10).contains(n))
+        });
+        assert_eq!(result.distance(&5), Some(&5));
+        assert_eq!(result.distance(&8), Some(&2));
+        assert_eq!(result.path_to(&8), Some(vec![10, 9, 8]));
+    }
+
+    fn weighted(n: &u32) -> Vec<(u32, u32)> {
+        // 0 -> 1 -> 2 -> 3 is cheaper than the direct 0 -> 3 edge
+        match n {
+            0 => vec![(1, 1), (3, 10)],
+            1 => vec![(2, 2)],
+            2 => vec![(3, 3)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn dijkstra_prefers_cheaper_paths() {
+        let result = dijkstra(0, weighted);
+        assert_eq!(result.distance(&3), Some(&6));
+        assert_eq!(result.path_to(&3), Some(vec![0, 1, 2, 3]));
+
+        let (found, result) = dijkstra_until(0, weighted, |n| *n >= 2).unwrap();
+        assert_eq!(found, 2);
+        assert_eq!(result.distance(&2), Some(&3));
+    }
+
+    #[test]
+    fn astar_matches_dijkstra() {
+        let goal = RowCol::<i32>::new(6, 9);
+        let wall = |p: &RowCol| *p.col() == 5 && *p.row() != 0;
+        let successors = |p: &RowCol| {
+            p.neighbors4()
+                .into_iter()
+                .filter(|n| (0..10).contains(n.row()) && (0..10).contains(n.col()) && !wall(n))
+                .map(|n| (n, 1))
+                .collect::<Vec<_>>()
+        };
+
+        let (_, with_heuristic) = astar(
+            RowCol::new(6, 0),
+            successors,
+            |p| p.manhatten_distance(&goal),
+            |p| *p == goal,
+        )
+        .unwrap();
+        let without_heuristic = dijkstra(RowCol::new(6, 0), successors);
+
+        assert_eq!(with_heuristic.distance(&goal), Some(&21));
+        assert_eq!(
+            with_heuristic.distance(&goal),
+            without_heuristic.distance(&goal)
+        );
+        assert!(with_heuristic.distances().len() < without_heuristic.distances().len());
+    }
+}
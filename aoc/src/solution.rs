@@ -0,0 +1,222 @@
+use std::{fmt::Display, str::FromStr};
+
+use crate::error::{Error, Result};
+
+/// Which of a day's input files a solution is run against.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InputKind {
+    Sample,
+    Puzzle,
+}
+
+impl InputKind {
+    pub fn file_name(&self) -> &'static str {
+        match self {
+            InputKind::Sample => "sample.txt",
+            InputKind::Puzzle => "input.txt",
+        }
+    }
+}
+
+impl Display for InputKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputKind::Sample => f.write_str("sample"),
+            InputKind::Puzzle => f.write_str("puzzle"),
+        }
+    }
+}
+
+impl FromStr for InputKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "sample" => Ok(InputKind::Sample),
+            "puzzle" | "input" => Ok(InputKind::Puzzle),
+            _ => Err(format!("'{}' is not one of sample, puzzle", s)),
+        }
+    }
+}
+
+/// One of the two parts of a day's puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+pub const EACH_PART: [Part; 2] = [Part::One, Part::Two];
+
+impl Part {
+    pub fn number(&self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" | "part1" => Ok(Part::One),
+            "2" | "part2" => Ok(Part::Two),
+            _ => Err(format!("'{}' is not one of part1, part2", s)),
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+/// What a part may return: its answer, or a [`Result`] of one for parts
+/// that parse their input with `?`.
+pub trait Answer {
+    fn into_answer(self) -> Result<String>;
+}
+
+macro_rules! display_answer {
+    ($($t:ty),+) => {
+        $(
+            impl Answer for $t {
+                fn into_answer(self) -> Result<String> {
+                    Ok(self.to_string())
+                }
+            }
+        )+
+    };
+}
+
+display_answer!(String, &str, char, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+impl<T: Answer, E> Answer for std::result::Result<T, E>
+where
+    Error: From<E>,
+{
+    fn into_answer(self) -> Result<String> {
+        self.map_err(Error::from)?.into_answer()
+    }
+}
+
+/// A day's puzzle solution, so that every challenge can be discovered and
+/// run through one registry.
+///
+/// Most days only need the input text and can use [`crate::solution!`]
+/// instead of implementing this by hand. The [`InputKind`] is passed along
+/// for puzzles whose parameters differ between the sample and the real input.
+pub trait Solution: Sync {
+    fn year(&self) -> u32;
+    fn day(&self) -> u32;
+    /// The directory holding the day's `sample.txt` and `input.txt`.
+    fn directory(&self) -> &'static str;
+    fn part1(&self, input: &str, kind: InputKind) -> Result<String>;
+    fn part2(&self, input: &str, kind: InputKind) -> Result<String>;
+
+    /// Solves a part, naming the input file in any parse error.
+    fn solve(&self, part: Part, input: &str, kind: InputKind) -> Result<String> {
+        match part {
+            Part::One => self.part1(input, kind),
+            Part::Two => self.part2(input, kind),
+        }
+        .map_err(|e| e.in_file(kind.file_name()))
+    }
+
+    fn load_input(&self, kind: InputKind) -> Result<String> {
+        crate::load_input(self.directory(), kind.file_name())
+    }
+}
+
+/// Declares `SOLUTION`, the [`Solution`] for the calling day crate, from a
+/// pair of `fn(&str) -> impl Answer` parts.
+///
+/// ```ignore
+/// aoc::solution!(2022, 14, part1, part2);
+/// ```
+#[macro_export]
+macro_rules! solution {
+    ($year:expr, $day:expr, $part1:path, $part2:path) => {
+        pub struct Day;
+
+        pub static SOLUTION: Day = Day;
+
+        impl $crate::solution::Solution for Day {
+            fn year(&self) -> u32 {
+                $year
+            }
+            fn day(&self) -> u32 {
+                $day
+            }
+            fn directory(&self) -> &'static str {
+                env!("CARGO_MANIFEST_DIR")
+            }
+            fn part1(
+                &self,
+                input: &str,
+                _kind: $crate::solution::InputKind,
+            ) -> $crate::error::Result<String> {
+                $crate::solution::Answer::into_answer($part1(input))
+            }
+            fn part2(
+                &self,
+                input: &str,
+                _kind: $crate::solution::InputKind,
+            ) -> $crate::error::Result<String> {
+                $crate::solution::Answer::into_answer($part2(input))
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use super::{InputKind, Part, Solution};
+    use crate::error::{parse_lines, ParseError};
+
+    fn double(input: &str) -> Result<i32, ParseError> {
+        let numbers = parse_lines(input, |line| Ok(line.trim().parse::<i32>()?))?;
+        Ok(numbers.iter().sum::<i32>() * 2)
+    }
+
+    fn echo(input: &str) -> String {
+        input.to_string()
+    }
+
+    solution!(2022, 1, double, echo);
+
+    #[test]
+    fn declared_solution() {
+        let solution: &dyn Solution = &SOLUTION;
+        assert_eq!((solution.year(), solution.day()), (2022, 1));
+        assert_eq!(solution.part1("21", InputKind::Sample).unwrap(), "42");
+        assert_eq!(solution.part2("abc", InputKind::Puzzle).unwrap(), "abc");
+        assert_eq!(solution.directory(), env!("CARGO_MANIFEST_DIR"));
+
+        let error = solution
+            .solve(Part::One, "21\nabc", InputKind::Sample)
+            .unwrap_err();
+        assert!(error.to_string().starts_with("sample.txt:2: invalid digit"));
+    }
+
+    #[test]
+    fn input_kind_names() {
+        for kind in [InputKind::Sample, InputKind::Puzzle] {
+            assert_eq!(kind.to_string().parse(), Ok(kind));
+        }
+        assert_eq!("input".parse(), Ok(InputKind::Puzzle));
+        assert!("real".parse::<InputKind>().is_err());
+    }
+
+    #[test]
+    fn part_names() {
+        assert_eq!("part1".parse(), Ok(Part::One));
+        assert_eq!("2".parse(), Ok(Part::Two));
+        assert!("part3".parse::<Part>().is_err());
+        assert_eq!(Part::Two.to_string(), "2");
+    }
+}
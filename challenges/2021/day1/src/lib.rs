@@ -0,0 +1,44 @@
+pub fn part1(input: &str) -> i32 {
+    let mut last_depth = None;
+    let mut increases = 0;
+    for line in input.lines() {
+        let depth: u32 = line.parse().unwrap();
+        if let Some(last) = &last_depth {
+            if depth > *last {
+                increases += 1;
+            }
+        }
+        last_depth = Some(depth);
+    }
+    increases
+}
+
+pub fn part2(input: &str) -> i32 {
+    let mut last_window = None;
+    let mut last_depths = Vec::new();
+    let mut increases = 0;
+    for line in input.lines() {
+        let depth: u32 = line.parse().unwrap();
+        last_depths.push(depth);
+        if last_depths.len() > 3 {
+            last_depths.remove(0);
+        }
+
+        if last_depths.len() == 3 {
+            let window: u32 = last_depths.iter().sum();
+
+            if let Some(last) = &last_window {
+                if window > *last {
+                    increases += 1;
+                }
+            }
+
+            last_window = Some(window);
+        }
+    }
+    increases
+}
+
+aoc::solution!(2021, 1, part1, part2);
+
+aoc::sample_tests!();
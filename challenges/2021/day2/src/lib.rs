@@ -0,0 +1,102 @@
+
+use serde_derive::Deserialize;
+
+#[derive(Debug, Deserialize)]
+struct SubPosition {
+    x: u32,
+    aim: i32,
+    depth: u32,
+}
+
+impl SubPosition {
+    pub fn new() -> Self {
+        Self {
+            x: 0,
+            aim: 0,
+            depth: 0,
+        }
+    }
+
+    pub fn move_by(&self, vector: &SubDirectionVector) -> Self {
+        match vector.direction {
+            SubDirection::Forward => Self {
+                x: self.x + vector.count,
+                aim: self.aim,
+                depth: self.depth,
+            },
+            SubDirection::Down => Self {
+                x: self.x,
+                aim: self.aim,
+                depth: self.depth + vector.count,
+            },
+            SubDirection::Up => Self {
+                x: self.x,
+                aim: self.aim,
+                depth: self.depth - vector.count,
+            },
+        }
+    }
+
+    pub fn move_by_aim(&self, vector: &SubDirectionVector) -> Self {
+        match vector.direction {
+            SubDirection::Forward => Self {
+                x: self.x + vector.count,
+                aim: self.aim,
+                depth: self
+                    .depth
+                    .checked_add_signed(self.aim * vector.count as i32)
+                    .unwrap(),
+            },
+            SubDirection::Down => Self {
+                x: self.x,
+                aim: self.aim + vector.count as i32,
+                depth: self.depth,
+            },
+            SubDirection::Up => Self {
+                x: self.x,
+                aim: self.aim - vector.count as i32,
+                depth: self.depth,
+            },
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum SubDirection {
+    Forward,
+    Down,
+    Up,
+}
+
+#[derive(Debug, Deserialize)]
+struct SubDirectionVector {
+    direction: SubDirection,
+    count: u32,
+}
+
+pub fn part1(input: &str) -> u32 {
+    let mut position = SubPosition::new();
+
+    for line in input.lines() {
+        let vector: SubDirectionVector = serde_scan::from_str(line).unwrap();
+        position = position.move_by(&vector);
+    }
+
+    position.x * position.depth
+}
+
+pub fn part2(input: &str) -> u32 {
+    let mut position = SubPosition::new();
+
+    for line in input.lines() {
+        let vector: SubDirectionVector = serde_scan::from_str(line).unwrap();
+        position = position.move_by_aim(&vector);
+    }
+
+    position.x * position.depth
+}
+
+aoc::solution!(2021, 2, part1, part2);
+
+aoc::sample_tests!();
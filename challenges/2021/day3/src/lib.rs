@@ -0,0 +1,107 @@
+use aoc::GetOrDefault;
+
+pub fn part1(input: &str) -> String {
+    let mut bitcount: Vec<usize> = Vec::new();
+    let mut count = 0;
+
+    for line in input.lines() {
+        for (i, bit) in line.chars().enumerate() {
+            let c = bitcount.get_mut_or_default(i);
+
+            if bit == '1' {
+                *c += 1;
+            }
+        }
+        count += 1;
+    }
+
+    let mut gamma = "".to_string();
+    let mut epsilon = "".to_string();
+    for bit in bitcount {
+        if bit > count / 2 {
+            gamma.push('1');
+            epsilon.push('0');
+        } else {
+            gamma.push('0');
+            epsilon.push('1');
+        }
+    }
+
+    let gamma_val = u32::from_str_radix(&gamma, 2).unwrap();
+    let epsion_val = u32::from_str_radix(&epsilon, 2).unwrap();
+
+    (gamma_val * epsion_val).to_string()
+}
+
+pub fn part2(input: &str) -> String {
+    let mut bitcount: Vec<usize> = Vec::new();
+
+    let values: Vec<String> = input.lines().map(|line| line.to_string()).collect();
+
+    let mut o2values = values.clone();
+    let mut co2values = values.clone();
+
+    let mut index = 0;
+    while o2values.len() > 1 {
+        bitcount.clear();
+        let mut count = 0;
+
+        for value in &o2values {
+            for (i, bit) in value.chars().enumerate() {
+                let c = bitcount.get_mut_or_default(i);
+
+                if bit == '1' {
+                    *c += 1;
+                }
+            }
+            count += 1;
+        }
+
+        let bit = bitcount.get(index).unwrap();
+
+        if *bit * 2 >= count {
+            o2values.retain(|e| e.chars().nth(index).unwrap() != '0');
+        } else {
+            o2values.retain(|e| e.chars().nth(index).unwrap() != '1');
+        }
+
+        index += 1;
+    }
+
+    index = 0;
+    while co2values.len() > 1 {
+        bitcount.clear();
+        let mut count = 0;
+
+        for value in &co2values {
+            for (i, bit) in value.chars().enumerate() {
+                let c = bitcount.get_mut_or_default(i);
+
+                if bit == '1' {
+                    *c += 1;
+                }
+            }
+            count += 1;
+        }
+
+        let bit = bitcount.get(index).unwrap();
+
+        if *bit * 2 < count {
+            co2values.retain(|e| e.chars().nth(index).unwrap() != '0');
+        } else {
+            co2values.retain(|e| e.chars().nth(index).unwrap() != '1');
+        }
+
+        index += 1;
+    }
+
+
+    let gamma_val = u32::from_str_radix(&o2values[0], 2).unwrap();
+    let epsion_val = u32::from_str_radix(&co2values[0], 2).unwrap();
+
+    (gamma_val * epsion_val).to_string()
+}
+
+aoc::solution!(2021, 3, part1, part2);
+
+aoc::sample_tests!();
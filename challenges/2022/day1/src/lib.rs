@@ -0,0 +1,25 @@
+use aoc::input::blocks;
+
+fn calories_per_elf(input: &str) -> Vec<u32> {
+    blocks(input)
+        .map(|elf| elf.lines().map(|line| line.trim().parse::<u32>().unwrap()).sum())
+        .collect()
+}
+
+pub fn part1(input: &str) -> String {
+    let calories = calories_per_elf(input);
+    let max_calories = calories.iter().max().unwrap();
+    max_calories.to_string()
+}
+
+pub fn part2(input: &str) -> String {
+    let mut sorted = calories_per_elf(input);
+    sorted.sort();
+
+    let top_three_total: u32 = sorted.iter().rev().take(3).sum();
+    top_three_total.to_string()
+}
+
+aoc::solution!(2022, 1, part1, part2);
+
+aoc::sample_tests!();
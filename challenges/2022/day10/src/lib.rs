@@ -0,0 +1,170 @@
+use std::fmt::Display;
+
+use aoc::{
+    coordinate::RowCol,
+    grid::{Grid, Tiles},
+    ocr::OcrError,
+    solution::InputKind,
+};
+use scan_fmt::scan_fmt;
+
+enum OpCode {
+    AddX(i32),
+    Noop,
+}
+
+impl OpCode {
+    pub fn from_str(s: &str) -> Option<OpCode> {
+        if let Ok(i) = scan_fmt!(s, "addx {d}", i32) {
+            Some(OpCode::AddX(i))
+        } else if s == "noop" {
+            Some(OpCode::Noop)
+        } else {
+            None
+        }
+    }
+
+    pub fn cycles(&self) -> i32 {
+        match self {
+            OpCode::AddX(_) => 2,
+            OpCode::Noop => 1,
+        }
+    }
+}
+
+impl Display for OpCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OpCode::AddX(i) => f.write_fmt(format_args!("addx {}", i)),
+            OpCode::Noop => f.write_fmt(format_args!("noop")),
+        }
+    }
+}
+
+fn parse_input(input: &str) -> Vec<OpCode> {
+    let mut instructions = Vec::new();
+    for line in input.lines() {
+        instructions.push(OpCode::from_str(line).unwrap());
+    }
+    instructions
+}
+
+pub fn part1(input: &str) -> String {
+    let input = parse_input(input);
+
+    let mut cycle_count = 1;
+    let mut x = 1;
+
+    let mut signal = Vec::new();
+
+    for i in input {
+        for _ in 0..i.cycles() {
+            let strength = x * cycle_count;
+            signal.push(strength);
+            cycle_count += 1;
+        }
+
+        match i {
+            OpCode::AddX(i) => x += i,
+            OpCode::Noop => {}
+        }
+    }
+    let sample_points = [20, 60, 100, 140, 180, 220];
+
+    let mut sum = 0;
+    for point in sample_points {
+        sum += signal[point - 1];
+    }
+    sum.to_string()
+}
+
+struct Sprite {
+    characters: String,
+    start_offset: i32,
+}
+
+impl Sprite {
+    pub fn new(characters: String, start_offset: i32) -> Self {
+        Self {
+            characters,
+            start_offset,
+        }
+    }
+
+    pub fn get_character(&self, offset: i32) -> Option<char> {
+        let index = offset + self.start_offset;
+        if index < 0 || index >= self.characters.len() as i32 {
+            None
+        } else {
+            Some(self.characters.chars().nth(index as usize).unwrap())
+        }
+    }
+}
+
+/// What the CRT draws, one tile per pixel.
+fn draw_screen(input: &str) -> Grid<char, RowCol> {
+    let input = parse_input(input);
+
+    let mut cycle_count = 1;
+    let mut x = 1;
+
+    let sprite = Sprite::new("###".to_string(), 1);
+
+    let mut screen = Grid::new();
+    for i in input {
+        for _ in 0..i.cycles() {
+            let row = (cycle_count - 1) / 40;
+            let col = (cycle_count - 1) % 40;
+
+            let ch = sprite.get_character(x - col).unwrap_or('.');
+            screen.insert(RowCol::new(row, col), ch);
+
+            cycle_count += 1;
+        }
+
+        match i {
+            OpCode::AddX(i) => x += i,
+            OpCode::Noop => {}
+        }
+    }
+
+    screen
+}
+
+pub fn part2(input: &str, kind: InputKind) -> Result<String, OcrError> {
+    let screen = draw_screen(input);
+
+    match kind {
+        // The sample draws a test pattern rather than letters, which is
+        // checked as it is
+        InputKind::Sample => {
+            let picture = screen.render(|_, t| t.copied().unwrap_or('.').to_string());
+            Ok(picture.trim_end().to_string())
+        }
+        InputKind::Puzzle => aoc::ocr::read_grid(&screen),
+    }
+}
+
+pub struct Day;
+
+pub static SOLUTION: Day = Day;
+
+impl aoc::solution::Solution for Day {
+    fn year(&self) -> u32 {
+        2022
+    }
+    fn day(&self) -> u32 {
+        10
+    }
+    fn directory(&self) -> &'static str {
+        env!("CARGO_MANIFEST_DIR")
+    }
+    fn part1(&self, input: &str, _kind: InputKind) -> aoc::error::Result<String> {
+        Ok(part1(input))
+    }
+    fn part2(&self, input: &str, kind: InputKind) -> aoc::error::Result<String> {
+        Ok(part2(input, kind)?)
+    }
+}
+
+aoc::sample_tests!();
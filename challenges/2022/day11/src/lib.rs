@@ -0,0 +1,255 @@
+use std::{
+    cell::RefCell,
+    collections::{HashMap, VecDeque},
+};
+
+use aoc::{
+    error::{LineError, ParseError},
+    input::{blocks, parse_blocks, BlockLines, FromBlock},
+};
+use scan_fmt::scan_fmt;
+
+#[derive(Debug)]
+enum Expression {
+    Add(Box<Expression>, Box<Expression>),
+    Multiply(Box<Expression>, Box<Expression>),
+    Constant(Worries),
+    Variable(String),
+}
+
+impl Expression {
+    pub fn parse(str: &str) -> Expression {
+        let trimmed = str.trim();
+        if let Ok((a, b)) = scan_fmt!(trimmed, "{} + {}", String, String) {
+            Expression::Add(
+                Box::new(Expression::parse(&a)),
+                Box::new(Expression::parse(&b)),
+            )
+        } else if let Ok((a, b)) = scan_fmt!(trimmed, "{} * {}", String, String) {
+            Expression::Multiply(
+                Box::new(Expression::parse(&a)),
+                Box::new(Expression::parse(&b)),
+            )
+        } else if let Ok(a) = scan_fmt!(trimmed, "{d}", Worries) {
+            Expression::Constant(a)
+        } else {
+            Expression::Variable(trimmed.to_string())
+        }
+    }
+    pub fn evaluate(&self, variables: &HashMap<String, Worries>) -> Worries {
+        match self {
+            Expression::Add(a, b) => a.evaluate(variables) + b.evaluate(variables),
+            Expression::Multiply(a, b) => a.evaluate(variables) * b.evaluate(variables),
+            Expression::Constant(c) => *c,
+            Expression::Variable(v) => *variables.get(v).unwrap(),
+        }
+    }
+}
+
+#[derive(Debug)]
+enum Test {
+    DivisibleBy(Worries),
+}
+
+type Worries = u64;
+
+impl Test {
+    pub fn parse(str: &str) -> Result<Test, LineError> {
+        if let Ok(v) = scan_fmt!(str, "divisible by {}", Worries) {
+            Ok(Test::DivisibleBy(v))
+        } else {
+            Err(LineError::new(format!("unexpected test: {}", str)))
+        }
+    }
+
+    pub fn evaluate(&self, value: Worries) -> bool {
+        match self {
+            Test::DivisibleBy(v) => value.is_multiple_of(*v),
+        }
+    }
+
+    pub fn divisible_amount(&self) -> Option<Worries> {
+        match self {
+            Test::DivisibleBy(v) => Some(*v),
+        }
+    }
+}
+
+#[derive(Debug)]
+enum Action {
+    ThrowTo(usize),
+}
+
+impl Action {
+    pub fn parse(str: &str) -> Result<Action, LineError> {
+        if let Ok(v) = scan_fmt!(str, "throw to monkey {}", usize) {
+            Ok(Action::ThrowTo(v))
+        } else {
+            Err(LineError::new(format!("unexpected action: {}", str)))
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Monkey {
+    index: usize,
+    items: VecDeque<Worries>,
+    operation: Expression,
+    test: Test,
+    true_action: Action,
+    false_action: Action,
+    inspection_count: usize,
+}
+
+impl FromBlock for Monkey {
+    fn from_block(lines: &mut BlockLines) -> Result<Self, LineError> {
+        let index = lines.line("Monkey")?.trim_end_matches(':').parse()?;
+        let items = lines
+            .line("Starting items:")?
+            .split(",")
+            .map(|i| i.trim().parse::<Worries>())
+            .collect::<Result<_, _>>()?;
+        let operation = Expression::parse(lines.line("Operation: new =")?);
+        let test = Test::parse(lines.line("Test:")?)?;
+        let true_action = Action::parse(lines.line("If true:")?)?;
+        let false_action = Action::parse(lines.line("If false:")?)?;
+
+        Ok(Monkey {
+            index,
+            items,
+            operation,
+            test,
+            true_action,
+            false_action,
+            inspection_count: 0,
+        })
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<RefCell<Monkey>>, ParseError> {
+    let monkeys: Vec<Monkey> = parse_blocks(input)?;
+
+    // Monkeys throw to each other by index, so they must be listed in order
+    for (expected, (block, monkey)) in blocks(input).zip(&monkeys).enumerate() {
+        if monkey.index != expected {
+            return Err(ParseError {
+                file: None,
+                line: block.line,
+                column: None,
+                text: block.lines().next().unwrap_or_default().to_string(),
+                message: format!("expected monkey {}", expected),
+            });
+        }
+    }
+    Ok(monkeys.into_iter().map(RefCell::new).collect())
+}
+
+pub fn part1(input: &str) -> Result<String, ParseError> {
+    let input = parse_input(input)?;
+
+    //println!("Monkeys: {input:?}");
+
+    for round in 0..20 {
+        for monkey in input.iter() {
+            let mut current_monkey = monkey.borrow_mut();
+
+            while let Some(item) = current_monkey.items.pop_front() {
+                let mut variables = HashMap::new();
+                variables.insert("old".to_string(), item);
+
+                // Inspect
+                let new = current_monkey.operation.evaluate(&variables);
+                let post_inspection = new / 3;
+                current_monkey.inspection_count += 1;
+
+                // Test
+                let action = if current_monkey.test.evaluate(post_inspection) {
+                    &current_monkey.true_action
+                } else {
+                    &current_monkey.false_action
+                };
+
+                // Act
+                match action {
+                    Action::ThrowTo(target) => {
+                        let mut target_monkey = input[*target ].borrow_mut();
+                        target_monkey.items.push_back(post_inspection);
+
+                        if round == 0 {
+                            //println!("Monkey {index}: {item} became {new} and then {post_inspection}, thrown to {target}")
+                        }
+                    }
+                }
+            }
+        }
+        /*
+        println!("Round {}", round + 1);
+        for (i, monkey) in input.iter().enumerate() {
+            println!("Monkey {}: {:?}", i, monkey.borrow().items);
+        }
+        */
+    }
+    let mut top_count: Vec<_> = input.iter().map(|m| m.borrow().inspection_count).collect();
+    top_count.sort();
+    let monkey_business: usize = top_count.iter().rev().take(2).product();
+    Ok(monkey_business.to_string())
+}
+
+pub fn part2(input: &str) -> Result<String, ParseError> {
+    let input = parse_input(input)?;
+    //println!("Monkeys: {input:?}");
+
+    let cap: Worries = input
+        .iter()
+        .map(|m| m.borrow().test.divisible_amount().unwrap())
+        .product();
+
+    for round in 0..10000 {
+        for monkey in input.iter() {
+            let mut current_monkey = monkey.borrow_mut();
+
+            while let Some(item) = current_monkey.items.pop_front() {
+                let mut variables = HashMap::new();
+                variables.insert("old".to_string(), item);
+
+                // Inspect
+                let new = current_monkey.operation.evaluate(&variables);
+                //let post_inspection = new / 3;
+                let post_inspection = new % cap;
+                current_monkey.inspection_count += 1;
+
+                // Test
+                let action = if current_monkey.test.evaluate(post_inspection) {
+                    &current_monkey.true_action
+                } else {
+                    &current_monkey.false_action
+                };
+
+                // Act
+                match action {
+                    Action::ThrowTo(target) => {
+                        let mut target_monkey = input[*target ].borrow_mut();
+                        target_monkey.items.push_back(post_inspection);
+
+                        if round == 0 {
+                            //println!("Monkey {index}: {item} became {new} and then {post_inspection}, thrown to {target}")
+                        }
+                    }
+                }
+            }
+        }
+
+        /*println!("Round {}", round + 1);
+        for (i, monkey) in input.iter().enumerate() {
+            println!("Monkey {}: {:?}", i, monkey.borrow().items);
+        }*/
+    }
+    let mut top_count: Vec<_> = input.iter().map(|m| m.borrow().inspection_count).collect();
+    top_count.sort();
+    let monkey_business: usize = top_count.iter().rev().take(2).product();
+    Ok(monkey_business.to_string())
+}
+
+aoc::solution!(2022, 11, part1, part2);
+
+aoc::sample_tests!();
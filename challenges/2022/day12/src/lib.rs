@@ -0,0 +1,74 @@
+use std::{
+    fmt::Display,
+    ops::Sub,
+};
+
+use aoc::{
+    coordinate::RowCol,
+    grid::{DenseGrid, Tiles},
+    search,
+};
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Default, Clone, Copy)]
+struct Height(char);
+
+impl Height {
+    pub fn new(c: char) -> Self {
+        Self(c)
+    }
+}
+
+impl Sub for Height {
+    type Output = i32;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.0 as i32 - rhs.0 as i32
+    }
+}
+
+impl Display for Height {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+fn parse_input(input: &str) -> (RowCol, RowCol, DenseGrid<Height, RowCol>) {
+    let (heights, markers) = DenseGrid::parse_with_markers(input, &['S', 'E'], |_, c| match c {
+        'S' => Height::new('a'),
+        'E' => Height::new('z'),
+        _ => Height::new(c),
+    });
+
+    (markers[&'S'][0], markers[&'E'][0], heights)
+}
+
+// Walking backwards from the end, a step is possible if we could have climbed
+// up from the next square to the current one
+fn can_step_down(from: &Height, to: &Height) -> bool {
+    *from - *to <= 1
+}
+
+pub fn part1(input: &str) -> String {
+    let (start, end, heights) = parse_input(input);
+
+    let distances = search::bfs(end, |p| heights.passable_neighbors(p, can_step_down));
+
+    distances.distance(&start).unwrap().to_string()
+}
+
+pub fn part2(input: &str) -> String {
+    let (_, end, heights) = parse_input(input);
+
+    let (nearest, distances) = search::bfs_until(
+        end,
+        |p| heights.passable_neighbors(p, can_step_down),
+        |p| heights.get(p) == Some(&Height::new('a')),
+    )
+    .unwrap();
+
+    distances.distance(&nearest).unwrap().to_string()
+}
+
+aoc::solution!(2022, 12, part1, part2);
+
+aoc::sample_tests!();
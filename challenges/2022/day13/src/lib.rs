@@ -0,0 +1,156 @@
+use std::{
+    cmp::Ordering,
+    fmt::Display,
+};
+
+use aoc::{
+    error::ParseError,
+    input::blocks,
+    parse::{integer, list, parse_all, ws, IResult},
+};
+use nom::{
+    branch::alt,
+    character::complete::{char, line_ending},
+    combinator::{cut, map, opt},
+    error::context,
+    sequence::{preceded, separated_pair, terminated},
+};
+
+type Unit = i32;
+
+#[derive(Clone, PartialEq, Eq)]
+enum Element {
+    List(Vec<Element>),
+    Value(Unit),
+}
+
+fn element_array(i: &str) -> IResult<'_, Vec<Element>> {
+    context(
+        "array",
+        preceded(
+            char('['),
+            cut(terminated(
+                map(opt(list(element)), Option::unwrap_or_default),
+                ws(char(']')),
+            )),
+        ),
+    )(i)
+}
+
+fn element(i: &str) -> IResult<'_, Element> {
+    ws(alt((
+        map(element_array, Element::List),
+        map(integer, Element::Value),
+    )))(i)
+}
+
+impl Element {
+    fn parse(i: &str) -> Result<Element, ParseError> {
+        parse_all(element, i)
+    }
+}
+
+impl Display for Element {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Element::List(l) => {
+                f.write_str("[")?;
+                let v: Vec<_> = l.iter().map(|e| e.to_string()).collect();
+                f.write_str(&v.join(", "))?;
+                f.write_str("]")
+            }
+            Element::Value(v) => v.fmt(f),
+        }
+    }
+}
+
+impl PartialOrd for Element {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Element {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Element::List(l), Element::List(r)) => {
+                let mut index = 0;
+                loop {
+                    if index < l.len() && index < r.len() {
+                        let c = l[index].cmp(&r[index]);
+                        if c != Ordering::Equal {
+                            return c;
+                        }
+                    } else {
+                        break;
+                    }
+                    index += 1;
+                }
+
+                l.len().cmp(&r.len())
+            }
+            (Element::List(_l), Element::Value(_r)) => self.cmp(&Element::List(vec![other.clone()])),
+            (Element::Value(_l), Element::List(_r)) => Element::List(vec![self.clone()]).cmp(other),
+            (Element::Value(l), Element::Value(r)) => l.cmp(r),
+        }
+    }
+}
+
+struct Pair {
+    left: Element,
+    right: Element,
+}
+
+fn parse_input(input: &str) -> Result<Vec<Pair>, ParseError> {
+    blocks(input)
+        .map(|block| {
+            let (left, right) = block.parse_all(separated_pair(element, line_ending, element))?;
+            Ok(Pair { left, right })
+        })
+        .collect()
+}
+
+pub fn part1(input: &str) -> Result<String, ParseError> {
+    let input = parse_input(input)?;
+
+    let mut correct_indicies = Vec::new();
+    for (index, pair) in input.iter().enumerate() {
+        if pair.left <= pair.right {
+            correct_indicies.push(index + 1);
+        }
+    }
+    Ok(correct_indicies.iter().sum::<usize>().to_string())
+}
+
+pub fn part2(input: &str) -> Result<String, ParseError> {
+    let input = parse_input(input)?;
+
+    let mut packets: Vec<_> = input
+        .iter()
+        .flat_map(|p| [p.left.clone(), p.right.clone()])
+        .collect();
+
+    let divider0 = Element::parse("[[2]]").unwrap();
+    let divider1 = Element::parse("[[6]]").unwrap();
+    packets.push(divider0.clone());
+    packets.push(divider1.clone());
+
+    packets.sort();
+
+    let divider0_position = packets
+        .iter()
+        .enumerate()
+        .find_map(|(i, e)| if *e == divider0 { Some(i) } else { None })
+        .unwrap();
+    let divider1_position = packets
+        .iter()
+        .enumerate()
+        .find_map(|(i, e)| if *e == divider1 { Some(i) } else { None })
+        .unwrap();
+
+    Ok(((divider0_position + 1) * (divider1_position + 1)).to_string())
+}
+
+aoc::solution!(2022, 13, part1, part2);
+
+aoc::sample_tests!();
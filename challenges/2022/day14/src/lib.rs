@@ -0,0 +1,231 @@
+use std::fmt::Display;
+
+use aoc::{
+    coordinate::{Coordinate, RectangularRange, XY},
+    grid::{DenseGrid, Tiles},
+    position::Direction8,
+};
+use scan_fmt::scan_fmt;
+
+type C = XY<Unit, true, false>;
+
+type Unit = i32;
+
+fn sand_origin() -> C {
+    C::new(500, 0)
+}
+
+/// Prints the map at every move of every grain, to watch the sand fall.
+const SHOW_FALLING_SAND: bool = false;
+
+// Sand tries to fall straight down first, then diagonally to the left, then to the right
+const FALL_DIRECTIONS: [Direction8; 3] = [
+    Direction8::Down,
+    Direction8::DownLeft,
+    Direction8::DownRight,
+];
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+enum Tile {
+    Rock,
+    #[default]
+    Air,
+    Sand,
+}
+
+impl Display for Tile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Tile::Rock => f.write_str("#"),
+            Tile::Air => f.write_str("."),
+            Tile::Sand => f.write_str("o"),
+        }
+    }
+}
+
+// 498,4 -> 498,6 -> 496,6
+// 503,4 -> 502,4 -> 502,9 -> 494,9
+fn parse_input(input: &str) -> DenseGrid<Tile, C> {
+    let mut map = DenseGrid::new();
+    for line in input.lines() {
+        let mut corners = Vec::new();
+        for cstr in line.split(" -> ") {
+            let (x, y) = scan_fmt!(cstr, "{},{}", Unit, Unit).unwrap();
+            let c = C::new(x, y);
+            corners.push(c);
+        }
+
+        for i in 1..corners.len() {
+            let start = &corners[i - 1];
+            let end = &corners[i];
+
+            let r = RectangularRange::from_points(&[*start, *end]);
+
+            for c in r.iter() {
+                *map.get_mut_or_default(&c) = Tile::Rock;
+            }
+        }
+    }
+    map
+}
+
+/// Drops sand until a grain falls past the lowest rock, returning how many
+/// came to rest.
+fn pour_into_abyss(map: &mut DenseGrid<Tile, C>) -> usize {
+    let mut resting_sand = 0;
+
+    // Each new sand
+    'each_sand: loop {
+        let mut sand = sand_origin();
+
+        // Each movement of the sand
+        'each_movement: loop {
+            if SHOW_FALLING_SAND {
+                print!("\n{}", render(map, Some(sand)));
+            }
+
+            let fall_options = FALL_DIRECTIONS.map(|d| sand.project8(d, 1));
+
+            let mut moved = false;
+            for next in fall_options {
+                if Tile::Air == map.get(&next).cloned().unwrap_or_default() {
+                    sand = next;
+                    moved = true;
+                    break;
+                }
+            }
+
+            if !moved {
+                *map.get_mut_or_default(&sand) = Tile::Sand;
+                resting_sand += 1;
+                break 'each_movement;
+            }
+
+            // Check for the edge
+            if map.range().vertical().bottom().unwrap() < sand.vertical() {
+                break 'each_sand;
+            }
+        }
+    }
+    resting_sand
+}
+
+/// The map as the puzzle draws it, with the source of the sand and the grain
+/// that is still falling.
+fn render(map: &DenseGrid<Tile, C>, falling: Option<C>) -> String {
+    map.display(|_c, t| t.cloned().unwrap_or_default().to_string())
+        .overlay([sand_origin()], '+')
+        .overlay(falling, '~')
+        .to_string()
+}
+
+pub fn part1(input: &str) -> String {
+    let mut map = parse_input(input);
+
+    let resting_sand = pour_into_abyss(&mut map);
+
+    resting_sand.to_string()
+}
+
+pub fn part2(input: &str) -> String {
+    let mut map = parse_input(input);
+
+    let mut resting_sand = 0;
+
+    let floor = *map.range().bottom_left().unwrap().vertical() + 2;
+
+    // Each new sand
+    'each_sand: loop {
+        let mut sand = sand_origin();
+
+        if let Some(Tile::Sand) = map.get(&sand).cloned() {
+            break 'each_sand;
+        }
+
+        // Each movement of the sand
+        'each_movement: loop {
+            if SHOW_FALLING_SAND {
+                print!("\n{}", render(&map, Some(sand)));
+            }
+
+            let fall_options = FALL_DIRECTIONS.map(|d| sand.project8(d, 1));
+
+            let mut moved = false;
+            for next in fall_options {
+                if *next.vertical() >= floor {
+                    break;
+                }
+                if Tile::Air == map.get(&next).cloned().unwrap_or_default() {
+                    sand = next;
+                    moved = true;
+                    break;
+                }
+            }
+
+            if !moved {
+                *map.get_mut_or_default(&sand) = Tile::Sand;
+                resting_sand += 1;
+                break 'each_movement;
+            }
+
+            // Check for the edge
+            //if map.range().vertical().bottom().unwrap() < sand.vertical() {
+            //    break 'each_sand;
+            //}
+        }
+    }
+
+    resting_sand.to_string()
+}
+
+aoc::solution!(2022, 14, part1, part2);
+
+aoc::sample_tests!();
+
+#[cfg(test)]
+mod test {
+    use aoc::{
+        coordinate::{HorizontalRange, RectangularRange, VerticalRange},
+        grid::Tiles,
+        range::Range,
+    };
+
+    use super::{parse_input, pour_into_abyss, render, sand_origin, C};
+
+    #[test]
+    fn sample_comes_to_rest_as_drawn() {
+        let mut map = parse_input(include_str!("../sample.txt"));
+        assert_eq!(pour_into_abyss(&mut map), 24);
+
+        let picture = map
+            .display(|_, t| t.cloned().unwrap_or_default().to_string())
+            .overlay([sand_origin()], '+')
+            .crop(RectangularRange::new(
+                HorizontalRange::new(Range::new(494..=503)),
+                VerticalRange::new(Range::new(0..=9)),
+            ))
+            .to_string();
+        assert_eq!(
+            picture,
+            "\
+......+...
+..........
+......o...
+.....ooo..
+....#ooo##
+...o#ooo#.
+..###ooo#.
+....oooo#.
+.o.ooooo#.
+#########.
+"
+        );
+
+        // The top of the pile, with a grain on its way down
+        let falling = render(&map, Some(C::new(497, 3)));
+        assert_eq!(
+            falling.lines().take(2).collect::<Vec<_>>(),
+            ["......o...", "...~.ooo.."]
+        );
+    }
+}
@@ -0,0 +1,163 @@
+use std::ops::Sub;
+
+use aoc::{
+    coordinate::{Coordinate, HorizontalRange, RectangularRange, VerticalRange, XY},
+    error::ParseError,
+    parse::{coordinate, lines, parse_all},
+    range::Range,
+    range_set::RangeSet,
+    solution::InputKind,
+};
+
+use nom::{bytes::complete::tag, combinator::map, sequence::tuple};
+
+type Unit = i32;
+type C = XY<Unit, true, false>;
+
+struct Sensor {
+    sensor_position: C,
+    neareset_beacon: C,
+}
+
+impl Sensor {
+    fn beacon_distance(&self) -> Unit {
+        self.sensor_position
+            .manhatten_distance(&self.neareset_beacon)
+    }
+    fn in_range(&self, c: &C) -> bool {
+        self.sensor_position.manhatten_distance(c)
+            <= self
+                .sensor_position
+                .manhatten_distance(&self.neareset_beacon)
+    }
+
+    // Iterates all of the values just outside the perimiter
+    fn perimeter(&self) -> impl Iterator<Item = C> + '_ {
+        let d = self.beacon_distance();
+        let upper_left = (0..d).map(move |i| self.sensor_position.up(i + 1).left(d - i));
+        let upper_right = (0..d).map(move |i| self.sensor_position.up(i + 1).right(d - i));
+        let lower_left = (0..d).map(move |i| self.sensor_position.down(i + 1).left(d - i));
+        let lower_right = (0..d).map(move |i| self.sensor_position.down(i + 1).right(d - i));
+
+        upper_left
+            .chain(upper_right)
+            .chain(lower_left)
+            .chain(lower_right)
+            .chain(std::iter::once(self.sensor_position.left(d + 1)))
+            .chain(std::iter::once(self.sensor_position.right(d + 1)))
+        // (d + 1) * 2 + 1
+
+        //   c
+        //  b#d
+        // a#S#e
+        //  h#f
+        //   g
+    }
+}
+
+//Sensor at x=2, y=18: closest beacon is at x=-2, y=15
+fn parse_input(input: &str) -> Result<Vec<Sensor>, ParseError> {
+    let sensor = map(
+        tuple((
+            tag("Sensor at "),
+            coordinate,
+            tag(": closest beacon is at "),
+            coordinate,
+        )),
+        |(_, sensor_position, _, neareset_beacon)| Sensor {
+            sensor_position,
+            neareset_beacon,
+        },
+    );
+    parse_all(lines(sensor), input)
+}
+
+pub fn part1(input: &str, target_row: Unit) -> Result<String, ParseError> {
+    let sensors = parse_input(input)?;
+
+    let mut covered = RangeSet::new();
+    for s in &sensors {
+        let distance_to_target_row = s.sensor_position.y().abs_diff(target_row) as Unit;
+
+        let max_blackout = s.beacon_distance() * 2 + 1;
+        let row_blackout = max_blackout - 2 * distance_to_target_row;
+        let max_blackout_start = s.sensor_position.x().sub(s.beacon_distance());
+        let row_blackout_start = max_blackout_start + distance_to_target_row;
+
+        if row_blackout > 0 {
+            let blackout_range = row_blackout_start..=(row_blackout_start + row_blackout - 1);
+            covered.insert(&Range::new(blackout_range));
+        }
+    }
+
+    for s in sensors
+        .iter()
+        .filter(|s| *s.neareset_beacon.y() == target_row)
+    {
+        let x = *s.neareset_beacon.x();
+        covered.remove(&Range::new(x..=x));
+    }
+
+    Ok(covered.len().to_string())
+}
+
+pub fn part2(input: &str, max_coordinate: Unit) -> Result<String, ParseError> {
+    let sensors = parse_input(input)?;
+
+    let max_range = Range::new(0..=max_coordinate);
+
+    let area = RectangularRange::<C>::new(
+        HorizontalRange::new(max_range.clone()),
+        VerticalRange::new(max_range.clone()),
+    );
+
+    let mut c = None;
+
+    'outer: for sensor in &sensors {
+        'points: for point in sensor.perimeter() {
+            if area.contains(&point) {
+                for sensor in &sensors {
+                    if sensor.in_range(&point) {
+                        continue 'points;
+                    }
+                }
+                c = Some(point);
+                break 'outer;
+            }
+        }
+    }
+    let f = *c.unwrap().x() as i64 * 4000000 + *c.unwrap().y() as i64;
+    Ok(f.to_string())
+}
+
+pub struct Day;
+
+pub static SOLUTION: Day = Day;
+
+// The row to check and the search area are given in the puzzle text rather
+// than the input, and are much smaller for the sample
+impl aoc::solution::Solution for Day {
+    fn year(&self) -> u32 {
+        2022
+    }
+    fn day(&self) -> u32 {
+        15
+    }
+    fn directory(&self) -> &'static str {
+        env!("CARGO_MANIFEST_DIR")
+    }
+    fn part1(&self, input: &str, kind: InputKind) -> aoc::error::Result<String> {
+        Ok(match kind {
+            InputKind::Sample => part1(input, 10)?,
+            InputKind::Puzzle => part1(input, 2000000)?,
+        })
+    }
+    fn part2(&self, input: &str, kind: InputKind) -> aoc::error::Result<String> {
+        Ok(match kind {
+            InputKind::Sample => part2(input, 20)?,
+            InputKind::Puzzle => part2(input, 4000000)?,
+        })
+    }
+}
+
+aoc::sample_tests!();
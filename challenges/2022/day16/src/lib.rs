@@ -0,0 +1,223 @@
+use std::{collections::HashMap, hash::Hash};
+
+use aoc::{
+    bitset::{Interner, SmallBitSet},
+    error::{parse_lines, ParseError},
+    graph::{Distances, Graph},
+    optimize::{self, Problem},
+};
+
+use scan_fmt::scan_fmt;
+
+type Unit = i32;
+
+struct Valve {
+    name: String,
+    rate: Unit,
+    tunnels_to: Vec<String>,
+}
+
+impl Hash for Valve {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.name.hash(state);
+    }
+}
+
+struct Maze {
+    valves: HashMap<String, Valve>,
+    /// The minutes it takes to walk between any two valves.
+    distances: Distances<String, Unit>,
+    /// The valves worth opening, numbered for [`State::opened_valves`].
+    significant: Interner<String>,
+    time_limit: Unit,
+}
+
+/// Where the search has got to: the valve just opened and when, and the
+/// pressure the opened valves will have released by the time limit.
+#[derive(Clone, Debug)]
+struct State {
+    position: String,
+    time: Unit,
+    opened_valves: SmallBitSet,
+    released: Unit,
+}
+
+impl State {
+    fn start() -> Self {
+        Self {
+            position: "AA".to_string(),
+            time: 0,
+            opened_valves: SmallBitSet::new(),
+            released: 0,
+        }
+    }
+}
+
+impl Problem for Maze {
+    type State = State;
+    type Move = String;
+    type Score = Unit;
+    type Key = (String, SmallBitSet);
+
+    // Travel to an unopened valve with a positive flow rate and open it, if
+    // there is time left for it to release anything
+    fn successors(&self, state: &State) -> Vec<(String, State)> {
+        self.unopened_valves(state)
+            .filter_map(|(index, v)| {
+                let time = state.time + self.distance_between(&state.position, &v.name) + 1;
+                if time >= self.time_limit {
+                    return None;
+                }
+                let next = State {
+                    position: v.name.clone(),
+                    time,
+                    opened_valves: state.opened_valves.with(index),
+                    released: state.released + v.rate * (self.time_limit - time),
+                };
+                Some((v.name.clone(), next))
+            })
+            .collect()
+    }
+
+    fn value(&self, state: &State) -> Unit {
+        state.released
+    }
+
+    // As if each unopened valve could be reached straight from here
+    fn bound(&self, state: &State) -> Unit {
+        let unopened: Unit = self
+            .unopened_valves(state)
+            .map(|(_, v)| {
+                let time = state.time + self.distance_between(&state.position, &v.name) + 1;
+                v.rate * (self.time_limit - time).max(0)
+            })
+            .sum();
+        state.released + unopened
+    }
+
+    fn key(&self, state: &State) -> Option<Self::Key> {
+        Some((state.position.clone(), state.opened_valves))
+    }
+
+    // Having opened the same valves, getting there sooner with more released
+    // can only be better
+    fn dominates(&self, a: &State, b: &State) -> bool {
+        a.time <= b.time && a.released >= b.released
+    }
+}
+
+impl Maze {
+    pub fn new(valves: HashMap<String, Valve>) -> Self {
+        let mut significant: Vec<_> = valves
+            .values()
+            .filter(|v| v.rate != 0)
+            .map(|v| v.name.clone())
+            .collect();
+        significant.sort();
+
+        let tunnels: Graph<String, Unit> = valves
+            .values()
+            .flat_map(|v| v.tunnels_to.iter().map(|to| (v.name.clone(), to.clone(), 1)))
+            .collect();
+        let distances = tunnels.floyd_warshall();
+
+        Self {
+            valves,
+            distances,
+            significant: significant.into_iter().collect(),
+            time_limit: 30,
+        }
+    }
+
+    pub fn significant_valves(&self) -> impl Iterator<Item = &Valve> {
+        self.significant.iter().map(|name| &self.valves[name])
+    }
+
+    /// The significant valves still closed in `state`, with their numbers.
+    fn unopened_valves<'a>(&'a self, state: &'a State) -> impl Iterator<Item = (usize, &'a Valve)> {
+        self.significant_valves()
+            .enumerate()
+            .filter(|(index, _)| !state.opened_valves.contains(*index))
+    }
+
+    pub fn distance_between(&self, from: &str, to: &str) -> Unit {
+        self.distances.get(from, to).unwrap()
+    }
+}
+
+// Valve AA has flow rate=0; tunnels lead to valves DD, II, BB
+fn parse_input(input: &str) -> Result<Maze, ParseError> {
+    let valves = parse_lines(input, |line| {
+        let (valve, rate, tunnels) = scan_fmt!(
+            line,
+            "Valve {} has flow rate={}; tunnel leads to valve {[A-Z, ]}",
+            String,
+            Unit,
+            String
+        )
+        .or_else(|_| {
+            scan_fmt!(
+                line,
+                "Valve {} has flow rate={}; tunnels lead to valves {[A-Z, ]}",
+                String,
+                Unit,
+                String
+            )
+        })?;
+        Ok((
+            valve.clone(),
+            Valve {
+                name: valve,
+                rate,
+                tunnels_to: tunnels.split(", ").map(|s| s.to_string()).collect(),
+            },
+        ))
+    })?;
+    Ok(Maze::new(valves.into_iter().collect()))
+}
+
+pub fn part1(input: &str) -> Result<String, ParseError> {
+    let maze = parse_input(input)?;
+
+    let best = optimize::maximize(&maze, State::start());
+    Ok(best.score.to_string())
+}
+
+pub fn part2(input: &str) -> Result<String, ParseError> {
+    let mut maze = parse_input(input)?;
+    maze.time_limit = 26;
+
+    // The most one of us can release by opening each set of valves
+    let mut released: HashMap<SmallBitSet, Unit> = HashMap::new();
+    let mut unexplored = vec![State::start()];
+    while let Some(state) = unexplored.pop() {
+        let best = released.entry(state.opened_valves).or_default();
+        *best = (*best).max(state.released);
+        unexplored.extend(maze.successors(&state).into_iter().map(|(_, next)| next));
+    }
+
+    // The most that can be released opening only valves from each set
+    let all: SmallBitSet = (0..maze.significant.len()).collect();
+    let mut best_within: HashMap<SmallBitSet, Unit> = HashMap::new();
+    for set in all.subsets() {
+        let best = set
+            .iter()
+            .map(|index| best_within[&set.without(index)])
+            .chain(released.get(&set).copied())
+            .max()
+            .unwrap_or(0);
+        best_within.insert(set, best);
+    }
+
+    // The elephant opens the valves that I leave
+    let best = all
+        .splits()
+        .map(|(mine, elephants)| best_within[&mine] + best_within[&elephants])
+        .max()
+        .unwrap();
+    Ok(best.to_string())
+}
+
+aoc::solution!(2022, 16, part1, part2);
+
+aoc::sample_tests!();
@@ -0,0 +1,109 @@
+use std::{collections::HashSet, hash::Hash};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Item {
+    character: char,
+}
+
+impl Item {
+    pub fn new(character: char) -> Self {
+        Self { character }
+    }
+
+    pub fn priority(&self) -> u32 {
+        if self.character >= 'a' && self.character <= 'z' {
+            1 + (self.character as u32 - 'a' as u32)
+        } else {
+            27 + (self.character as u32 - 'A' as u32)
+        }
+    }
+}
+
+pub struct Rucksack {
+    compartments: [Vec<Item>; 2],
+}
+
+impl Rucksack {
+    pub fn compartment_from_str(string: &str) -> Vec<Item> {
+        string.chars().map(Item::new).collect()
+    }
+    pub fn new(string: &str) -> Self {
+        assert!(string.len().is_multiple_of(2));
+        Self {
+            compartments: [
+                Self::compartment_from_str(&string[0..string.len() / 2]),
+                Self::compartment_from_str(&string[string.len() / 2..]),
+            ],
+        }
+    }
+    pub fn items_in_both(&self) -> HashSet<Item> {
+        self.compartments[0]
+            .iter()
+            .filter_map(|e| {
+                if self.compartments[1].contains(e) {
+                    Some(e.clone())
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+
+    pub fn all_item_types(&self) -> HashSet<Item> {
+        self.compartments[0]
+            .iter()
+            .chain(self.compartments[1].iter())
+            .cloned()
+            .collect()
+    }
+}
+
+pub fn part1(input: &str) -> String {
+    let mut priorities = 0;
+    for line in input.lines() {
+        let r = Rucksack::new(line);
+        let common_items = r.items_in_both();
+        assert_eq!(common_items.len(), 1);
+        let common_item = common_items.iter().next().unwrap();
+
+        priorities += common_item.priority();
+    }
+    priorities.to_string()
+}
+
+pub fn part2(input: &str) -> String {
+    let mut iter = input.lines();
+    let mut priorities = 0;
+    while let Some(first) = iter.next() {
+        let second = iter.next().unwrap();
+        let third = iter.next().unwrap();
+
+        let rucksacks = (
+            Rucksack::new(first),
+            Rucksack::new(second),
+            Rucksack::new(third),
+        );
+
+        let s0: HashSet<Item> = rucksacks
+            .0
+            .all_item_types()
+            .intersection(&rucksacks.1.all_item_types())
+            .cloned()
+            .collect();
+        let s1: HashSet<Item> = s0
+            .intersection(&rucksacks.2.all_item_types())
+            .cloned()
+            .collect();
+
+        assert_eq!(1, s1.len());
+
+        let badge = s1.iter().next().unwrap();
+
+        priorities += badge.priority();
+    }
+    priorities.to_string()
+}
+
+aoc::solution!(2022, 3, part1, part2);
+
+aoc::sample_tests!();
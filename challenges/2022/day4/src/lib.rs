@@ -0,0 +1,61 @@
+use scan_fmt::scan_fmt;
+use serde_derive::Deserialize;
+
+#[derive(Debug, Deserialize)]
+struct Range {
+    start: u32,
+    end: u32,
+}
+
+impl Range {}
+
+#[derive(Debug, Deserialize)]
+struct Pair {
+    first: Range,
+    second: Range,
+}
+
+impl Pair {
+    pub fn complete_overlapped(&self) -> bool {
+        (self.first.start >= self.second.start && self.first.end <= self.second.end) || (self.second.start >= self.first.start && self.second.end <= self.first.end)
+    }
+
+    pub fn any_overlapped(&self) -> bool {
+        (self.first.start >= self.second.start && self.first.start <= self.second.end)
+            || (self.first.end >= self.second.start && self.first.end <= self.second.end) || (self.first.start < self.second.start && self.first.end > self.second.end)
+    }
+}
+
+pub fn part1(input: &str) -> i32 {
+    let mut completely_overlapped = 0;
+    for line in input.lines() {
+        let (a, b, c, d) = scan_fmt!(line, "{d}-{d},{d}-{d}", u32, u32, u32, u32).unwrap();
+        let pair = Pair {
+            first: Range { start: a, end: b },
+            second: Range { start: c, end: d },
+        };
+        if pair.complete_overlapped() {
+            completely_overlapped += 1;
+        }
+    }
+    completely_overlapped
+}
+
+pub fn part2(input: &str) -> i32 {
+    let mut any_overlapped = 0;
+    for line in input.lines() {
+        let (a, b, c, d) = scan_fmt!(line, "{d}-{d},{d}-{d}", u32, u32, u32, u32).unwrap();
+        let pair = Pair {
+            first: Range { start: a, end: b },
+            second: Range { start: c, end: d },
+        };
+        if pair.any_overlapped() {
+            any_overlapped += 1;
+        }
+    }
+    any_overlapped
+}
+
+aoc::solution!(2022, 4, part1, part2);
+
+aoc::sample_tests!();
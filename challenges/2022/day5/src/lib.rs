@@ -0,0 +1,125 @@
+use aoc::{
+    error::{LineError, ParseError},
+    input::{blocks, columns, Block, Columns},
+};
+use scan_fmt::scan_fmt;
+
+/// A move between two stacks, by their index rather than their label.
+struct Instruction {
+    count: usize,
+    source: usize,
+    dest: usize,
+}
+
+struct Input<'a> {
+    stacks: Vec<Vec<&'a str>>,
+    instructions: Vec<Instruction>,
+}
+
+/// Reads the drawing and the moves, checking that every move has the crates
+/// it takes and that every stack ends with a crate on top.
+fn parse_input(input: &str) -> Result<Input<'_>, ParseError> {
+    let mut sections = blocks(input);
+    let drawing = sections.next().unwrap_or(Block { line: 1, text: "" });
+    let Columns { labels, stacks } = columns(drawing.text)?;
+
+    let stack = |label: usize| {
+        labels
+            .iter()
+            .position(|l| l.parse() == Ok(label))
+            .ok_or_else(|| LineError::new(format!("there is no stack {}", label)))
+    };
+    let mut heights: Vec<usize> = stacks.iter().map(Vec::len).collect();
+    let instructions = match sections.next() {
+        Some(moves) => moves.parse_lines(|line| {
+            let (count, source_label, dest_label) =
+                scan_fmt!(line, "move {d} from {d} to {d}", usize, usize, usize)?;
+            let (source, dest) = (stack(source_label)?, stack(dest_label)?);
+            if heights[source] < count {
+                return Err(LineError::new(format!(
+                    "stack {} only has {} crates",
+                    source_label, heights[source]
+                )));
+            }
+            heights[source] -= count;
+            heights[dest] += count;
+            Ok(Instruction {
+                count,
+                source,
+                dest,
+            })
+        })?,
+        None => Vec::new(),
+    };
+
+    if let Some(empty) = heights.iter().position(|&h| h == 0) {
+        let footer = drawing.lines().count().max(1);
+        return Err(ParseError {
+            file: None,
+            line: drawing.line + footer - 1,
+            column: None,
+            text: drawing.lines().last().unwrap_or_default().to_string(),
+            message: format!("stack {} ends with no crate on top", labels[empty]),
+        });
+    }
+    Ok(Input {
+        stacks,
+        instructions,
+    })
+}
+
+fn top_crates(stacks: &[Vec<&str>]) -> String {
+    stacks.iter().filter_map(|s| s.last().copied()).collect()
+}
+
+pub fn part1(input: &str) -> Result<String, ParseError> {
+    let parsed_input = parse_input(input)?;
+    let mut stacks = parsed_input.stacks;
+    let instructions = parsed_input.instructions;
+
+    for instruction in instructions {
+        let source = &mut stacks[instruction.source];
+        let moved = source.split_off(source.len() - instruction.count);
+        // One crate at a time, so the top one ends up at the bottom
+        stacks[instruction.dest].extend(moved.into_iter().rev());
+    }
+    Ok(top_crates(&stacks))
+}
+
+pub fn part2(input: &str) -> Result<String, ParseError> {
+    let parsed_input = parse_input(input)?;
+    let mut stacks = parsed_input.stacks;
+    let instructions = parsed_input.instructions;
+
+    for instruction in instructions {
+        let source = &mut stacks[instruction.source];
+        let mut moved = source.split_off(source.len() - instruction.count);
+        stacks[instruction.dest].append(&mut moved);
+    }
+    Ok(top_crates(&stacks))
+}
+
+aoc::solution!(2022, 5, part1, part2);
+
+aoc::sample_tests!();
+
+#[cfg(test)]
+mod test {
+    use super::part1;
+
+    const DRAWING: &str = "    [D]\n[N] [C]\n[Z] [M] [P]\n 1   2   3\n\n";
+
+    #[test]
+    fn impossible_moves_are_parse_errors() {
+        let error = |moves: &str| part1(&format!("{}{}", DRAWING, moves)).unwrap_err();
+
+        let e = error("move 1 from 2 to 1\nmove 4 from 1 to 2\n");
+        assert_eq!((e.line, e.message.as_str()), (7, "stack 1 only has 3 crates"));
+
+        let e = error("move 1 from 0 to 1\n");
+        assert_eq!((e.line, e.message.as_str()), (6, "there is no stack 0"));
+
+        let e = error("move 1 from 3 to 1\n");
+        assert_eq!((e.line, e.message.as_str()), (4, "stack 3 ends with no crate on top"));
+    }
+}
@@ -0,0 +1,30 @@
+use std::collections::HashSet;
+
+
+// scan_fmt!(line, "move {d} from {d} to {d}", usize, usize, usize)
+
+pub fn part1(input: &str) -> String {
+    for i in 4..input.len() {
+        let sop = &input[i - 4..i];
+        let chars: HashSet<char> = sop.chars().collect();
+        if chars.len() == 4 {
+            return i.to_string();
+        }
+    }
+    "".to_string()
+}
+
+pub fn part2(input: &str) -> String {
+    for i in 14..input.len() {
+        let sop = &input[i - 14..i];
+        let chars: HashSet<char> = sop.chars().collect();
+        if chars.len() == 14 {
+            return i.to_string();
+        }
+    }
+    "".to_string()
+}
+
+aoc::solution!(2022, 6, part1, part2);
+
+aoc::sample_tests!();
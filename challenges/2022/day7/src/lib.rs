@@ -0,0 +1,159 @@
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    rc::{Rc, Weak},
+};
+
+use scan_fmt::scan_fmt;
+
+struct Directory {
+    children: RefCell<HashMap<String, Node>>,
+    parent: Option<Weak<Directory>>,
+}
+
+struct File {
+    size: usize,
+}
+
+#[derive(Clone)]
+enum Node {
+    File(Rc<File>),
+    Directory(Rc<Directory>),
+}
+
+impl Directory {
+    pub fn new(parent: Option<&Rc<Directory>>) -> Rc<Self> {
+        Rc::new(Self {
+            children: RefCell::new(HashMap::new()),
+            parent: parent.map(Rc::downgrade),
+        })
+    }
+
+    pub fn total_size(self: &Rc<Directory>) -> usize {
+        let mut size = 0;
+        for child in self.children.borrow().values() {
+            match child {
+                Node::File(f) => size += f.size,
+                Node::Directory(d) => size += d.total_size(),
+            }
+        }
+        size
+    }
+
+    pub fn add_file(self: &Rc<Directory>, name: String, size: usize) -> Rc<File> {
+        if let Some(Node::File(f)) = self.children.borrow().get(&name) {
+            return f.clone();
+        }
+
+        let file = Rc::new(File { size });
+
+        self.children
+            .borrow_mut()
+            .insert(name, Node::File(file.clone()));
+
+        file
+    }
+
+    pub fn add_directory(self: &Rc<Directory>, name: String) -> Rc<Directory> {
+        if let Some(Node::Directory(f)) = self.children.borrow().get(&name) {
+            return f.clone();
+        }
+
+        let file = Directory::new(Some(self));
+
+        self.children
+            .borrow_mut()
+            .insert(name, Node::Directory(file.clone()));
+
+        file
+    }
+
+    pub fn parent(self: &Rc<Directory>) -> Option<Rc<Directory>> {
+        self.parent.as_ref().map(|p| p.upgrade().unwrap())
+    }
+
+    pub fn flatten(self: &Rc<Directory>) -> Vec<Node> {
+        let mut v = Vec::new();
+        v.push(Node::Directory(self.clone()));
+
+        for n in self.children.borrow().values() {
+            if let Node::Directory(d) = n {
+                v.append(&mut d.flatten());
+            } else {
+                v.push(n.clone())
+            }
+        }
+        v
+    }
+}
+
+// scan_fmt!(line, "move {d} from {d} to {d}", usize, usize, usize)
+
+fn get_tree(input: &str) -> Rc<Directory> {
+    let root = Directory::new(None);
+
+    let mut current = root.clone();
+
+    for line in input.lines() {
+        if line == "$ cd /" {
+            current = root.clone();
+        } else if line == "$ cd .." {
+            current = current.parent().unwrap();
+        } else if let Ok(path) = scan_fmt!(line, "$ cd {s}", String) {
+            current = current.add_directory(path);
+        } else if line == "$ ls" {
+        } else if let Ok((size, name)) = scan_fmt!(line, "{d} {s}", usize, String) {
+            current.add_file(name, size);
+        } else if let Ok(dir) = scan_fmt!(line, "dir {s}", String) {
+            current.add_directory(dir);
+        } else {
+            panic!("Unexpected input");
+        }
+    }
+
+    root
+}
+pub fn part1(input: &str) -> String {
+    let root = get_tree(input);
+
+    let mut sum = 0;
+    for n in root.flatten() {
+        if let Node::Directory(d) = n {
+            if d.total_size() <= 100000 {
+                sum += d.total_size();
+            }
+        }
+    }
+
+    sum.to_string()
+}
+
+pub fn part2(input: &str) -> String {
+    let root = get_tree(input);
+    let total_disk = 70000000;
+    let min_unused = 30000000;
+
+    let must_free = min_unused - (total_disk - root.total_size());
+
+    let mut smallest_dir = None;
+
+    for n in root.flatten() {
+        if let Node::Directory(d) = n {
+            if d.total_size() >= must_free {
+                if let Some(smallest) = smallest_dir {
+                    if d.total_size() < smallest {
+                        smallest_dir = Some(d.total_size());
+                    }
+                } else {
+                    smallest_dir = Some(d.total_size());
+                }
+            }
+        }
+    }
+
+    smallest_dir.unwrap().to_string()
+}
+
+aoc::solution!(2022, 7, part1, part2);
+
+aoc::sample_tests!();
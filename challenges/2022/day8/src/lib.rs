@@ -0,0 +1,104 @@
+use std::collections::HashMap;
+
+use aoc::{
+    coordinate::{Coordinate, RowCol},
+    grid::DenseGrid,
+    position::EACH_DIRECTION,
+};
+
+#[derive(Debug)]
+enum Visibility {
+    Unknown,
+    Visible,
+}
+
+#[derive(Debug)]
+struct Tree {
+    height: u32,
+    visible: Visibility,
+}
+
+type Field = DenseGrid<Tree, RowCol>;
+
+trait FieldTrait {
+    fn total_visible(&self) -> usize;
+}
+
+impl FieldTrait for Field {
+    fn total_visible(&self) -> usize {
+        self.tiles()
+            .map(|t| match t.visible {
+                Visibility::Unknown => 0,
+                Visibility::Visible => 1,
+            })
+            .sum()
+    }
+}
+
+fn parse_input(input: &str) -> Field {
+    DenseGrid::parse(input, |_, char| Tree {
+        height: char as u32 - '0' as u32,
+        visible: Visibility::Unknown,
+    })
+}
+
+pub fn part1(input: &str) -> String {
+    let mut field = parse_input(input);
+
+    for direction in EACH_DIRECTION {
+        for mut position in field.range().edge_positions(direction.opposite()) {
+            //println!("Position: {:?} direction {:?}", position, direction);
+            let mut highest = field.get(&position).unwrap().height;
+            field.get_mut(&position).unwrap().visible = Visibility::Visible;
+
+            position = position.project(direction, 1);
+            while field.range().contains(&position) {
+                let tree = field.get_mut(&position).unwrap();
+                if tree.height > highest {
+                    tree.visible = Visibility::Visible;
+                    highest = tree.height;
+                }
+                position = position.project(direction, 1);
+            }
+        }
+    }
+    field.total_visible().to_string()
+}
+
+pub fn part2(input: &str) -> String {
+    let field = parse_input(input);
+
+    let mut high_score = 0;
+    for position in field.range().iter() {
+        let mut visible_distances = HashMap::new();
+        for direction in EACH_DIRECTION {
+            let mut visible_trees = 0;
+            let current_tree_height = field.get(&position).unwrap().height;
+
+            let mut position = position;
+            position = position.project(direction, 1);
+            while field.range().contains(&position) {
+                let observed_tree = field.get(&position).unwrap();
+
+                visible_trees += 1;
+
+                if observed_tree.height >= current_tree_height {
+                    break;
+                }
+                position = position.project(direction, 1);
+            }
+            visible_distances.insert(direction, visible_trees);
+        }
+
+        let score = visible_distances.values().product();
+        if high_score < score {
+            high_score = score;
+        }
+    }
+
+    high_score.to_string()
+}
+
+aoc::solution!(2022, 8, part1, part2);
+
+aoc::sample_tests!();
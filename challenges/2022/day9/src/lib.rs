@@ -0,0 +1,126 @@
+use aoc::{
+    coordinate::{Coordinate, RowCol},
+    error::{parse_lines, LineError, ParseError},
+    grid::Grid,
+    position::Direction,
+};
+use scan_fmt::scan_fmt;
+
+struct Instruction {
+    direction: Direction,
+    count: i32,
+}
+fn parse_input(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    parse_lines(input, |line| {
+        let (d, c) = scan_fmt!(line, "{s} {d}", String, i32)?;
+        let direction = match d.as_str() {
+            "U" => Direction::Up,
+            "D" => Direction::Down,
+            "L" => Direction::Left,
+            "R" => Direction::Right,
+            _ => return Err(LineError::new(format!("unknown direction '{}'", d)).at(1)),
+        };
+        Ok(Instruction {
+            direction,
+            count: c,
+        })
+    })
+}
+
+pub fn part1(input: &str) -> Result<String, ParseError> {
+    let input = parse_input(input)?;
+
+    let mut grid = Grid::new();
+    let mut head = RowCol::new(0, 0);
+    let mut tail = RowCol::new(0, 0);
+
+    for instruction in input {
+        for _ in 0..instruction.count {
+            head = head.project(instruction.direction, 1);
+
+            if !tail.neighbors8().contains(&head) && tail != head {
+                tail = tail.project8(tail.direction8_towards(&head), 1);
+            }
+
+            grid.get_mut_or_default(&head);
+
+            *grid.get_mut_or_default(&tail) = true;
+
+            /*
+            println!("{:?} {}", instruction.direction, instruction.count);
+            grid.print(|c, t| {
+
+                if c == tail && c == head {
+                    "X".to_string()
+                } else
+                if c == tail {
+                    "T".to_string()
+                } else if c == head {
+                    "H".to_string()
+                } else if let Some(true) = t {
+                    "#".to_string()
+                } else {
+                    ".".to_string()
+                }
+            });
+            println!("");
+            */
+        }
+    }
+
+    Ok(grid.tiles().filter(|t| **t).count().to_string())
+}
+
+pub fn part2(input: &str) -> Result<String, ParseError> {
+    let input = parse_input(input)?;
+
+    let mut grid = Grid::new();
+    let mut rope = [RowCol::new(0, 0); 10];
+    //let mut head = RowCol::new(0, 0);
+    //let mut tail = RowCol::new(0, 0);
+
+    for instruction in input {
+        for _ in 0..instruction.count {
+            rope[0] = rope[0].project(instruction.direction, 1);
+
+            for knot in 1..10 {
+                let head = rope[knot - 1];
+                let tail = &mut rope[knot];
+                if !tail.neighbors8().contains(&head) && *tail != head {
+                    *tail = tail.project8(tail.direction8_towards(&head), 1);
+                }
+
+                grid.get_mut_or_default(&head);
+                grid.get_mut_or_default(tail);
+            }
+
+            *grid.get_mut_or_default(&rope[9]) = true;
+
+            /*
+            println!("{:?} {}", instruction.direction, instruction.count);
+            grid.print(|c, t| {
+
+                if c == tail && c == head {
+                    "X".to_string()
+                } else
+                if c == tail {
+                    "T".to_string()
+                } else if c == head {
+                    "H".to_string()
+                } else if let Some(true) = t {
+                    "#".to_string()
+                } else {
+                    ".".to_string()
+                }
+            });
+            println!("");
+            */
+        }
+    }
+
+    Ok(grid.tiles().filter(|t| **t).count().to_string())
+}
+
+aoc::solution!(2022, 9, part1, part2);
+
+aoc::sample_tests!();
@@ -0,0 +1,103 @@
+use std::{
+    error::Error,
+    path::PathBuf,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::config::Config;
+
+const USER_AGENT: &str = concat!("aoc-prep/", env!("CARGO_PKG_VERSION"));
+
+/// Talks to the puzzle site described by a [`Config`]. Pages are cached on
+/// disk, and requests are spaced out by the configured rate limit.
+pub struct Client {
+    config: Config,
+}
+
+impl Client {
+    pub fn new(config: Config) -> Self {
+        Self { config }
+    }
+
+    /// Fetches `path` from the site, or from the cache if it was fetched
+    /// before.
+    pub fn get_cached(&self, path: &str) -> Result<String, Box<dyn Error>> {
+        let cache_file = self.cache_file(path);
+        if cache_file.exists() {
+            return Ok(std::fs::read_to_string(cache_file)?);
+        }
+
+        let body = self.send(ureq::get(&self.url(path)), None)?;
+
+        std::fs::create_dir_all(&self.config.cache_dir)?;
+        std::fs::write(cache_file, &body)?;
+        Ok(body)
+    }
+
+    /// Posts a form to `path`, which is never cached.
+    pub fn post_form(&self, path: &str, fields: &[(&str, &str)]) -> Result<String, Box<dyn Error>> {
+        self.send(ureq::post(&self.url(path)), Some(fields))
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("{}/{}", self.config.base_url.trim_end_matches('/'), path)
+    }
+
+    fn cache_file(&self, path: &str) -> PathBuf {
+        self.config.cache_dir.join(path.replace('/', "_"))
+    }
+
+    /// Sends a request with the session cookie once the rate limit allows it.
+    fn send(
+        &self,
+        request: ureq::Request,
+        form: Option<&[(&str, &str)]>,
+    ) -> Result<String, Box<dyn Error>> {
+        let request = request
+            .set("Cookie", &format!("session={}", self.config.session()?))
+            .set("User-Agent", USER_AGENT);
+        let url = request.url().to_string();
+
+        self.wait_for_rate_limit()?;
+        let response = match form {
+            Some(fields) => request.send_form(fields),
+            None => request.call(),
+        };
+        self.record_request()?;
+
+        match response {
+            Ok(response) => Ok(response.into_string()?),
+            Err(ureq::Error::Status(code, _)) => {
+                Err(format!("{} answered {}, is the session token valid?", url, code).into())
+            }
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    // The time of the last request is kept in the cache so that the limit
+    // holds across runs
+    fn last_request_file(&self) -> PathBuf {
+        self.config.cache_dir.join("last-request")
+    }
+
+    fn wait_for_rate_limit(&self) -> Result<(), Box<dyn Error>> {
+        let Ok(text) = std::fs::read_to_string(self.last_request_file()) else {
+            return Ok(());
+        };
+        let last = Duration::from_millis(text.trim().parse()?);
+        let since = SystemTime::now()
+            .duration_since(UNIX_EPOCH)?
+            .saturating_sub(last);
+        if since < self.config.rate_limit {
+            std::thread::sleep(self.config.rate_limit - since);
+        }
+        Ok(())
+    }
+
+    fn record_request(&self) -> Result<(), Box<dyn Error>> {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH)?;
+        std::fs::create_dir_all(&self.config.cache_dir)?;
+        std::fs::write(self.last_request_file(), now.as_millis().to_string())?;
+        Ok(())
+    }
+}
@@ -0,0 +1,132 @@
+use std::{
+    error::Error,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use toml_edit::Document;
+
+/// Kept in the workspace root and out of version control, as it holds the
+/// session token.
+pub const CONFIG_FILE: &str = ".aoc-prep.toml";
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// How to reach the puzzle site, read from [`CONFIG_FILE`]:
+///
+/// ```toml
+/// session = "53616c7465645f5f..."
+/// base_url = "https://adventofcode.com"
+/// cache_dir = ".aoc-cache"
+/// rate_limit_secs = 5
+/// ```
+///
+/// Every key is optional. `AOC_SESSION` and `AOC_BASE_URL` override the file.
+#[derive(Debug, Clone)]
+pub struct Config {
+    pub base_url: String,
+    pub session: Option<String>,
+    pub cache_dir: PathBuf,
+    /// The least time to leave between two requests to the site.
+    pub rate_limit: Duration,
+}
+
+impl Config {
+    pub fn new(root: &Path) -> Self {
+        Self {
+            base_url: DEFAULT_BASE_URL.to_string(),
+            session: None,
+            cache_dir: root.join(".aoc-cache"),
+            rate_limit: Duration::from_secs(5),
+        }
+    }
+
+    /// Reads the settings of the workspace at `root`.
+    pub fn load(root: &Path) -> Result<Self, Box<dyn Error>> {
+        let path = root.join(CONFIG_FILE);
+        let mut config = if path.exists() {
+            let text = std::fs::read_to_string(&path)?;
+            Self::parse(root, &text).map_err(|e| format!("{}: {}", path.display(), e))?
+        } else {
+            Self::new(root)
+        };
+
+        if let Ok(session) = std::env::var("AOC_SESSION") {
+            config.session = Some(session);
+        }
+        if let Ok(base_url) = std::env::var("AOC_BASE_URL") {
+            config.base_url = base_url;
+        }
+        Ok(config)
+    }
+
+    pub fn parse(root: &Path, text: &str) -> Result<Self, Box<dyn Error>> {
+        let doc: Document = text.parse()?;
+        let mut config = Self::new(root);
+
+        let string = |key: &str| -> Result<Option<String>, Box<dyn Error>> {
+            match doc.get(key) {
+                Some(item) => Ok(Some(
+                    item.as_str()
+                        .ok_or_else(|| format!("{} is not a string", key))?
+                        .to_string(),
+                )),
+                None => Ok(None),
+            }
+        };
+        if let Some(session) = string("session")? {
+            config.session = Some(session);
+        }
+        if let Some(base_url) = string("base_url")? {
+            config.base_url = base_url;
+        }
+        if let Some(cache_dir) = string("cache_dir")? {
+            config.cache_dir = root.join(cache_dir);
+        }
+        if let Some(item) = doc.get("rate_limit_secs") {
+            let secs = item
+                .as_integer()
+                .map(|i| i as f64)
+                .or_else(|| item.as_float())
+                .filter(|secs| *secs >= 0.0)
+                .ok_or("rate_limit_secs is not a positive number")?;
+            config.rate_limit = Duration::from_secs_f64(secs);
+        }
+        Ok(config)
+    }
+
+    pub fn session(&self) -> Result<&str, Box<dyn Error>> {
+        self.session.as_deref().ok_or_else(|| {
+            format!(
+                "no session token, set AOC_SESSION or session in {}",
+                CONFIG_FILE
+            )
+            .into()
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{path::Path, time::Duration};
+
+    use super::{Config, DEFAULT_BASE_URL};
+
+    #[test]
+    fn parse() {
+        let root = Path::new("/work");
+        let config = Config::parse(
+            root,
+            "session = \"abc\"\ncache_dir = \"cache\"\nrate_limit_secs = 0.5\n",
+        )
+        .unwrap();
+        assert_eq!(config.session.as_deref(), Some("abc"));
+        assert_eq!(config.base_url, DEFAULT_BASE_URL);
+        assert_eq!(config.cache_dir, root.join("cache"));
+        assert_eq!(config.rate_limit, Duration::from_millis(500));
+
+        assert!(Config::parse(root, "").unwrap().session().is_err());
+        assert!(Config::parse(root, "session = 1").is_err());
+        assert!(Config::parse(root, "rate_limit_secs = -1").is_err());
+    }
+}
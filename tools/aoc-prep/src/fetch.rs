@@ -0,0 +1,168 @@
+use std::{error::Error, path::Path};
+
+use crate::client::Client;
+
+/// The first `<pre><code>` block of a puzzle page, which is the sample input
+/// for most puzzles.
+pub fn extract_sample(page: &str) -> Option<String> {
+    let start = page.find("<pre><code>")? + "<pre><code>".len();
+    let end = start + page[start..].find("</code></pre>")?;
+    Some(unescape(&strip_tags(&page[start..end])))
+}
+
+// Samples often highlight parts of the input with <em> tags
+fn strip_tags(html: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text
+}
+
+fn unescape(html: &str) -> String {
+    html.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+// A file from the template is empty until it has been filled in
+fn is_present(path: &Path) -> bool {
+    std::fs::metadata(path).is_ok_and(|m| m.len() > 0)
+}
+
+/// Downloads the input and sample of a day into `day_dir`, leaving any that
+/// are already there alone. Returns the files written.
+pub fn fetch_day(
+    client: &Client,
+    day_dir: &Path,
+    year: u32,
+    day: u32,
+) -> Result<Vec<String>, Box<dyn Error>> {
+    let mut written = Vec::new();
+
+    let input_file = day_dir.join("input.txt");
+    if !is_present(&input_file) {
+        let input = client.get_cached(&format!("{}/day/{}/input", year, day))?;
+        std::fs::write(&input_file, input)?;
+        written.push("input.txt".to_string());
+    }
+
+    let sample_file = day_dir.join("sample.txt");
+    if !is_present(&sample_file) {
+        let page = client.get_cached(&format!("{}/day/{}", year, day))?;
+        let sample = extract_sample(&page).ok_or("the puzzle page has no <pre><code> block")?;
+        std::fs::write(&sample_file, sample)?;
+        written.push("sample.txt".to_string());
+    }
+    Ok(written)
+}
+
+#[cfg(test)]
+mod test {
+    use std::{
+        path::PathBuf,
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+        time::Duration,
+    };
+
+    use tiny_http::{Response, Server};
+
+    use super::{extract_sample, fetch_day};
+    use crate::{client::Client, config::Config};
+
+    const PAGE: &str = "<article><p>For example:</p>\
+        <pre><code>498,4 -&gt; 498,6\n<em>503,4</em> -&gt; 502,4\n</code></pre>\
+        <pre><code>ignored</code></pre></article>";
+
+    #[test]
+    fn sample_from_page() {
+        assert_eq!(
+            extract_sample(PAGE).unwrap(),
+            "498,4 -> 498,6\n503,4 -> 502,4\n"
+        );
+        assert_eq!(extract_sample("<p>no code</p>"), None);
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-prep-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    // Stands in for the puzzle site, counting the requests that reach it
+    fn serve() -> (String, Arc<AtomicUsize>) {
+        let server = Server::http("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", server.server_addr().to_ip().unwrap());
+        let requests = Arc::new(AtomicUsize::new(0));
+        let counter = requests.clone();
+        std::thread::spawn(move || {
+            for request in server.incoming_requests() {
+                counter.fetch_add(1, Ordering::SeqCst);
+                let cookie = request
+                    .headers()
+                    .iter()
+                    .find(|h| h.field.equiv("Cookie"))
+                    .map(|h| h.value.to_string());
+                let response = match (request.url(), cookie.as_deref()) {
+                    (_, Some("session=secret")) if request.url().ends_with("/input") => {
+                        Response::from_string("1\n2\n3\n")
+                    }
+                    ("/2022/day/14", Some("session=secret")) => Response::from_string(PAGE),
+                    _ => {
+                        Response::from_string("Puzzle inputs differ by user.").with_status_code(400)
+                    }
+                };
+                request.respond(response).unwrap();
+            }
+        });
+        (url, requests)
+    }
+
+    #[test]
+    fn fetch_from_stand_in() {
+        let (url, requests) = serve();
+        let root = temp_dir("fetch");
+        let day_dir = root.join("day14");
+        std::fs::create_dir_all(&day_dir).unwrap();
+        std::fs::write(day_dir.join("input.txt"), "").unwrap();
+
+        let mut config = Config::new(&root);
+        config.base_url = url;
+        config.session = Some("secret".to_string());
+        config.rate_limit = Duration::ZERO;
+        let client = Client::new(config.clone());
+
+        let written = fetch_day(&client, &day_dir, 2022, 14).unwrap();
+        assert_eq!(written, ["input.txt", "sample.txt"]);
+        let input = std::fs::read_to_string(day_dir.join("input.txt")).unwrap();
+        assert_eq!(input, "1\n2\n3\n");
+        assert_eq!(requests.load(Ordering::SeqCst), 2);
+
+        // Files already present are kept, and missing ones come from the cache
+        std::fs::write(day_dir.join("input.txt"), "edited").unwrap();
+        std::fs::remove_file(day_dir.join("sample.txt")).unwrap();
+        let written = fetch_day(&client, &day_dir, 2022, 14).unwrap();
+        assert_eq!(written, ["sample.txt"]);
+        let input = std::fs::read_to_string(day_dir.join("input.txt")).unwrap();
+        assert_eq!(input, "edited");
+        assert_eq!(requests.load(Ordering::SeqCst), 2);
+
+        config.session = Some("expired".to_string());
+        let client = Client::new(config);
+        assert!(fetch_day(&client, &root.join("day15"), 2022, 15).is_err());
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -1,8 +1,24 @@
-use clap::{command, Parser};
+use std::{error::Error, path::Path};
+
+use clap::{Parser, Subcommand};
+
+mod client;
+mod config;
+mod fetch;
+mod scaffold;
+mod submit;
+mod workspace;
 
 /// Program to generate aoc template projects
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+/// The puzzle a command applies to
+#[derive(clap::Args, Debug, Clone)]
 struct Args {
     /// AOC puzzle year
     #[arg(short, long, default_value_t = 2021)]
@@ -12,45 +28,229 @@ struct Args {
     day: u32,
 }
 
-fn find_workspace(args: &Args) {
-    for path in std::env::current_dir().unwrap().ancestors() {
-        let cargo_toml = path.join("Cargo.toml");
-        if cargo_toml.exists() {
-            let content = std::fs::read_to_string(&cargo_toml).unwrap();
-            let mut doc: toml_edit::Document = content.parse().unwrap();
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Create a day from the template and add it to the workspace and runner
+    New {
+        #[command(flatten)]
+        args: Args,
+        /// Puzzle title, for the crate documentation
+        #[arg(short, long)]
+        title: Option<String>,
+        /// Replace the code of an existing day. Its input files and answers
+        /// are kept
+        #[arg(long)]
+        force: bool,
+    },
+    /// Download the puzzle input and sample of a day created with `new`
+    Fetch {
+        #[command(flatten)]
+        args: Args,
+        /// Site to fetch from, instead of the configured one
+        #[arg(long)]
+        base_url: Option<String>,
+    },
+    /// Submit an answer, unless earlier guesses show it to be wrong
+    Submit {
+        #[command(flatten)]
+        args: Args,
+        /// Puzzle part the answer is for
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        answer: String,
+    },
+    /// Choose which days the workspace builds
+    Workspace {
+        #[command(subcommand)]
+        command: WorkspaceCommand,
+    },
+}
 
-            if doc.contains_key("workspace") {
-                println!("Found workspace at {}", cargo_toml.display());
+#[derive(Subcommand, Debug)]
+enum WorkspaceCommand {
+    /// Put a day back in the workspace and the runner
+    Enable {
+        #[command(flatten)]
+        args: Args,
+    },
+    /// Take a day out of the workspace and the runner, so that it isn't built
+    Disable {
+        #[command(flatten)]
+        args: Args,
+    },
+    /// Make a day the only default member, so that a plain `cargo run` or
+    /// `cargo test` builds it alone
+    Focus {
+        #[command(flatten)]
+        args: Args,
+    },
+    /// Make the runner the default member again
+    Unfocus,
+    /// Show every day and whether it is enabled or focused
+    List {
+        /// AOC puzzle year
+        #[arg(short, long)]
+        year: Option<u32>,
+    },
+}
 
-                let workspace = doc.get_mut("workspace").unwrap();
-                let members = workspace
-                    .get_mut("members")
-                    .unwrap()
-                    .as_array_mut()
-                    .unwrap();
+fn new_day(args: &Args, title: Option<&str>, force: bool) -> Result<(), Box<dyn Error>> {
+    let manifest = workspace::find_workspace()?;
+    let root = manifest
+        .parent()
+        .ok_or("workspace manifest has no parent")?;
+    println!("Found workspace at {}", manifest.display());
 
-                let new_path = format!("challenges/{}/day{}", args.year, args.day);
-                members.push(&new_path);
+    let member = workspace::member_path(args.year, args.day);
+    let crate_name = scaffold::crate_name(args.year, args.day);
+    let data = scaffold::template_data(args.year, args.day, title);
+    for file in scaffold::render(&scaffold::template_dir(), &root.join(&member), &data, force)? {
+        println!("  wrote {}", file.display());
+    }
 
-                let default_members = workspace
-                    .get_mut("default-members")
-                    .unwrap()
-                    .as_array_mut()
-                    .unwrap();
+    let mut doc = workspace::read_toml(&manifest)?;
+    if workspace::add_member(&mut doc, &member)? {
+        workspace::write_toml(&manifest, &doc)?;
+        println!("  added {} to the workspace", member);
+    }
+    scaffold::register_solution(root, &crate_name, &member)?;
+    println!("  registered {} with the runner", crate_name);
+    Ok(())
+}
 
-                assert_eq!(1, default_members.len());
+fn fetch_day(args: &Args, base_url: Option<&str>) -> Result<(), Box<dyn Error>> {
+    let manifest = workspace::find_workspace()?;
+    let root = manifest
+        .parent()
+        .ok_or("workspace manifest has no parent")?;
 
-                default_members.replace(0, &new_path);
-                println!("{}", doc.to_string());
+    let mut config = config::Config::load(root)?;
+    if let Some(base_url) = base_url {
+        config.base_url = base_url.to_string();
+    }
+
+    let member = workspace::member_path(args.year, args.day);
+    let day_dir = root.join(&member);
+    if !day_dir.exists() {
+        return Err(format!("{} doesn't exist, create it with aoc-prep new", member).into());
+    }
+
+    let client = client::Client::new(config);
+    let written = fetch::fetch_day(&client, &day_dir, args.year, args.day)?;
+    if written.is_empty() {
+        println!("{} already has its input and sample", member);
+    }
+    for file in written {
+        println!("  wrote {}/{}", member, file);
+    }
+    Ok(())
+}
+
+fn submit_answer(args: &Args, part: u8, answer: &str) -> Result<(), Box<dyn Error>> {
+    let manifest = workspace::find_workspace()?;
+    let root = manifest
+        .parent()
+        .ok_or("workspace manifest has no parent")?;
+
+    let member = workspace::member_path(args.year, args.day);
+    let day_dir = root.join(&member);
+    if !day_dir.exists() {
+        return Err(format!("{} doesn't exist, create it with aoc-prep new", member).into());
+    }
+
+    let client = client::Client::new(config::Config::load(root)?);
+    let outcome = submit::submit(&client, &day_dir, args.year, args.day, part, answer)?;
+    println!("{}", outcome);
+    Ok(())
+}
+
+// The member of a day that exists on disk
+fn existing_member(root: &Path, args: &Args) -> Result<String, Box<dyn Error>> {
+    let member = workspace::member_path(args.year, args.day);
+    if !root.join(&member).exists() {
+        return Err(format!("{} doesn't exist, create it with aoc-prep new", member).into());
+    }
+    Ok(member)
+}
+
+fn enable_day(
+    root: &Path,
+    doc: &mut toml_edit::Document,
+    args: &Args,
+) -> Result<String, Box<dyn Error>> {
+    let member = existing_member(root, args)?;
+    if workspace::add_member(doc, &member)? {
+        println!("  added {} to the workspace", member);
+    }
+    let crate_name = workspace::package_name(root, &member)?;
+    scaffold::register_solution(root, &crate_name, &member)?;
+    println!("  registered {} with the runner", crate_name);
+    Ok(member)
+}
+
+fn manage_workspace(command: &WorkspaceCommand) -> Result<(), Box<dyn Error>> {
+    let manifest = workspace::find_workspace()?;
+    let root = manifest
+        .parent()
+        .ok_or("workspace manifest has no parent")?;
+    let mut doc = workspace::read_toml(&manifest)?;
+
+    match command {
+        WorkspaceCommand::Enable { args } => {
+            enable_day(root, &mut doc, args)?;
+        }
+        WorkspaceCommand::Disable { args } => {
+            let member = existing_member(root, args)?;
+            if workspace::remove_member(&mut doc, &member)? {
+                println!("  removed {} from the workspace", member);
+            }
+            if workspace::default_members(&doc).contains(&member.as_str()) {
+                workspace::set_default_members(&mut doc, &[workspace::RUNNER])?;
+                println!("  focused {} again", workspace::RUNNER);
             }
+            let crate_name = workspace::package_name(root, &member)?;
+            scaffold::unregister_solution(root, &crate_name)?;
+            println!("  unregistered {} from the runner", crate_name);
+        }
+        WorkspaceCommand::Focus { args } => {
+            let member = enable_day(root, &mut doc, args)?;
+            workspace::set_default_members(&mut doc, &[&member])?;
+            println!("  focused {}", member);
+        }
+        WorkspaceCommand::Unfocus => {
+            workspace::set_default_members(&mut doc, &[workspace::RUNNER])?;
+            println!("  focused {}", workspace::RUNNER);
+        }
+        WorkspaceCommand::List { year } => {
+            let members = workspace::members(&doc);
+            let default_members = workspace::default_members(&doc);
+            for (y, d) in workspace::challenges(root)? {
+                if year.is_some_and(|year| year != y) {
+                    continue;
+                }
+                let member = workspace::member_path(y, d);
+                let state = if default_members.contains(&member.as_str()) {
+                    "focused"
+                } else if members.contains(&member.as_str()) {
+                    "enabled"
+                } else {
+                    "disabled"
+                };
+                println!("{:<24} {}", member, state);
+            }
+            return Ok(());
         }
     }
+    workspace::write_toml(&manifest, &doc)
 }
 
-fn main() {
-    let args = Args::parse();
+fn main() -> Result<(), Box<dyn Error>> {
+    let cli = Cli::parse();
 
-    find_workspace(&args);
-
-    println!("Hello, world!");
+    match &cli.command {
+        Command::New { args, title, force } => new_day(args, title.as_deref(), *force),
+        Command::Fetch { args, base_url } => fetch_day(args, base_url.as_deref()),
+        Command::Submit { args, part, answer } => submit_answer(args, *part, answer),
+        Command::Workspace { command } => manage_workspace(command),
+    }
 }
@@ -0,0 +1,180 @@
+use std::{
+    collections::BTreeMap,
+    error::Error,
+    path::{Path, PathBuf},
+};
+
+use handlebars::Handlebars;
+use toml_edit::{InlineTable, Value};
+
+use crate::workspace;
+
+/// Puzzle data rather than code, which `--force` leaves as it is.
+const DATA_FILES: [&str; 3] = ["input.txt", "sample.txt", "answers.toml"];
+
+pub fn template_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("template")
+}
+
+pub fn crate_name(year: u32, day: u32) -> String {
+    format!("aoc{}day{}", year, day)
+}
+
+/// The values available to the template files.
+pub fn template_data(year: u32, day: u32, title: Option<&str>) -> BTreeMap<&'static str, String> {
+    let mut data = BTreeMap::new();
+    data.insert("crate_name", crate_name(year, day));
+    data.insert("year", year.to_string());
+    data.insert("day", day.to_string());
+    if let Some(title) = title {
+        data.insert("title", title.to_string());
+    }
+    data
+}
+
+fn template_files(dir: &Path, files: &mut Vec<PathBuf>) -> Result<(), Box<dyn Error>> {
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            template_files(&path, files)?;
+        } else {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+/// Renders every file of `template` into `destination`, returning the files
+/// written.
+pub fn render(
+    template: &Path,
+    destination: &Path,
+    data: &BTreeMap<&'static str, String>,
+    force: bool,
+) -> Result<Vec<PathBuf>, Box<dyn Error>> {
+    if destination.exists() && !force {
+        return Err(format!(
+            "{} already exists, use --force to overwrite it",
+            destination.display()
+        )
+        .into());
+    }
+
+    let mut handlebars = Handlebars::new();
+    handlebars.set_strict_mode(true);
+    handlebars.register_escape_fn(handlebars::no_escape);
+
+    let mut files = Vec::new();
+    template_files(template, &mut files)?;
+    files.sort();
+
+    let mut written = Vec::new();
+    for file in files {
+        let target = destination.join(file.strip_prefix(template)?);
+        let is_data = file
+            .file_name()
+            .and_then(|n| n.to_str())
+            .is_some_and(|n| DATA_FILES.contains(&n));
+        if is_data && target.exists() {
+            continue;
+        }
+
+        let content = std::fs::read_to_string(&file)?;
+        let rendered = handlebars
+            .render_template(&content, data)
+            .map_err(|e| format!("{}: {}", file.display(), e))?;
+        if let Some(parent) = target.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(&target, rendered)?;
+        written.push(target);
+    }
+    Ok(written)
+}
+
+/// Adds the day to the `aoc` runner's dependencies and registry so that it
+/// can be run alongside the others.
+pub fn register_solution(
+    root: &Path,
+    crate_name: &str,
+    member: &str,
+) -> Result<(), Box<dyn Error>> {
+    let manifest = root.join(workspace::RUNNER).join("Cargo.toml");
+    let mut doc = workspace::read_toml(&manifest)?;
+    let dependencies = doc["dependencies"]
+        .as_table_mut()
+        .ok_or("aoc-runner has no [dependencies] table")?;
+    if !dependencies.contains_key(crate_name) {
+        let mut dependency = InlineTable::new();
+        dependency.insert("path", Value::from(format!("../../{}", member)));
+        dependency.fmt();
+        dependencies[crate_name] = toml_edit::value(dependency);
+        workspace::write_toml(&manifest, &doc)?;
+    }
+
+    let registry = root.join(workspace::RUNNER).join("src/registry.rs");
+    let source = std::fs::read_to_string(&registry)?;
+    let entry = format!("        &{}::SOLUTION,\n", crate_name);
+    if !source.contains(&entry) {
+        let end = source
+            .find("    ];\n")
+            .ok_or("can't find the end of the solutions list")?;
+        let mut source = source;
+        source.insert_str(end, &entry);
+        std::fs::write(&registry, source)?;
+    }
+    Ok(())
+}
+
+/// Takes the day out of the `aoc` runner, so that it is no longer built.
+pub fn unregister_solution(root: &Path, crate_name: &str) -> Result<(), Box<dyn Error>> {
+    let manifest = root.join(workspace::RUNNER).join("Cargo.toml");
+    let mut doc = workspace::read_toml(&manifest)?;
+    let dependencies = doc["dependencies"]
+        .as_table_mut()
+        .ok_or("aoc-runner has no [dependencies] table")?;
+    if dependencies.remove(crate_name).is_some() {
+        workspace::write_toml(&manifest, &doc)?;
+    }
+
+    let registry = root.join(workspace::RUNNER).join("src/registry.rs");
+    let source = std::fs::read_to_string(&registry)?;
+    let entry = format!("        &{}::SOLUTION,\n", crate_name);
+    if source.contains(&entry) {
+        std::fs::write(&registry, source.replace(&entry, ""))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::{render, template_data, template_dir};
+
+    #[test]
+    fn renders_template() {
+        let destination = std::env::temp_dir().join(format!("aoc-prep-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&destination);
+
+        let data = template_data(2022, 17, Some("Pyroclastic Flow"));
+        let written = render(&template_dir(), &destination, &data, false).unwrap();
+        assert!(written.contains(&destination.join("src/lib.rs")));
+
+        let manifest = std::fs::read_to_string(destination.join("Cargo.toml")).unwrap();
+        assert!(manifest.contains("name = \"aoc2022day17\""));
+        let lib = std::fs::read_to_string(destination.join("src/lib.rs")).unwrap();
+        assert!(lib.starts_with("//! AOC 2022 day 17: Pyroclastic Flow\n"));
+        assert!(lib.contains("aoc::solution!(2022, 17, part1, part2);"));
+        assert!(lib.contains("aoc::sample_tests!(sample);"));
+        let answers = std::fs::read_to_string(destination.join("answers.toml")).unwrap();
+        assert!(answers.contains("[sample]\npart1 = \"\"\npart2 = \"\"\n"));
+
+        // An existing day is only replaced when forced, and keeps its input
+        assert!(render(&template_dir(), &destination, &data, false).is_err());
+        std::fs::write(destination.join("input.txt"), "1\n2\n").unwrap();
+        render(&template_dir(), &destination, &data, true).unwrap();
+        let input = std::fs::read_to_string(destination.join("input.txt")).unwrap();
+        assert_eq!(input, "1\n2\n");
+
+        std::fs::remove_dir_all(&destination).unwrap();
+    }
+}
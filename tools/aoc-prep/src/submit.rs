@@ -0,0 +1,362 @@
+use std::{error::Error, fmt::Display, path::Path, time::Duration};
+
+use toml_edit::{value, Document, Item, Table};
+
+use crate::{client::Client, workspace};
+
+/// Guesses are kept next to the day's input, one table per part mapping each
+/// answer to its outcome:
+///
+/// ```toml
+/// [part1]
+/// "1234" = "too high"
+/// "1000" = "correct"
+/// ```
+pub const HISTORY_FILE: &str = "guesses.toml";
+
+/// What the site made of an answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    /// Answered too recently, try again after this long.
+    Wait(Duration),
+    /// The part was already solved, or isn't unlocked yet.
+    WrongLevel,
+}
+
+impl Outcome {
+    /// Reads the article of the site's answer page.
+    pub fn parse(page: &str) -> Result<Self, Box<dyn Error>> {
+        if page.contains("That's the right answer") {
+            Ok(Outcome::Correct)
+        } else if page.contains("That's not the right answer") {
+            if page.contains("your answer is too high") {
+                Ok(Outcome::TooHigh)
+            } else if page.contains("your answer is too low") {
+                Ok(Outcome::TooLow)
+            } else {
+                Ok(Outcome::Wrong)
+            }
+        } else if page.contains("You gave an answer too recently") {
+            Ok(Outcome::Wait(parse_wait(page).unwrap_or_default()))
+        } else if page.contains("You don't seem to be solving the right level") {
+            Ok(Outcome::WrongLevel)
+        } else {
+            Err("can't make sense of the answer page".into())
+        }
+    }
+
+    /// How the outcome is kept in the history, for those worth keeping.
+    fn name(&self) -> Option<&'static str> {
+        match self {
+            Outcome::Correct => Some("correct"),
+            Outcome::TooHigh => Some("too high"),
+            Outcome::TooLow => Some("too low"),
+            Outcome::Wrong => Some("wrong"),
+            Outcome::Wait(_) | Outcome::WrongLevel => None,
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        [
+            Outcome::Correct,
+            Outcome::TooHigh,
+            Outcome::TooLow,
+            Outcome::Wrong,
+        ]
+        .into_iter()
+        .find(|o| o.name() == Some(name))
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Outcome::Wait(d) => write!(f, "answered too recently, wait {}s", d.as_secs()),
+            Outcome::WrongLevel => f.write_str("not the part being solved"),
+            _ => f.write_str(self.name().unwrap_or_default()),
+        }
+    }
+}
+
+// "You have 4m 39s left to wait."
+fn parse_wait(page: &str) -> Option<Duration> {
+    let end = page.find(" left to wait")?;
+    let start = page[..end].rfind("You have ")? + "You have ".len();
+    let mut secs = 0;
+    for part in page[start..end].split_whitespace() {
+        let (n, unit) = part.split_at(part.find(|c: char| !c.is_ascii_digit())?);
+        secs += n.parse::<u64>().ok()?
+            * match unit {
+                "h" => 3600,
+                "m" => 60,
+                "s" => 1,
+                _ => return None,
+            };
+    }
+    Some(Duration::from_secs(secs))
+}
+
+/// Somewhere to send answers. The puzzle site in practice, or a stand-in for
+/// testing.
+pub trait AnswerService {
+    fn submit(
+        &self,
+        year: u32,
+        day: u32,
+        part: u8,
+        answer: &str,
+    ) -> Result<Outcome, Box<dyn Error>>;
+}
+
+impl AnswerService for Client {
+    fn submit(
+        &self,
+        year: u32,
+        day: u32,
+        part: u8,
+        answer: &str,
+    ) -> Result<Outcome, Box<dyn Error>> {
+        let level = part.to_string();
+        let page = self.post_form(
+            &format!("{}/day/{}/answer", year, day),
+            &[("level", &level), ("answer", answer)],
+        )?;
+        Outcome::parse(&page)
+    }
+}
+
+/// Every answer given for a day so far.
+pub struct History {
+    doc: Document,
+}
+
+impl History {
+    pub fn parse(text: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self { doc: text.parse()? })
+    }
+
+    pub fn load(day_dir: &Path) -> Result<Self, Box<dyn Error>> {
+        let path = day_dir.join(HISTORY_FILE);
+        if path.exists() {
+            Ok(Self {
+                doc: workspace::read_toml(&path)?,
+            })
+        } else {
+            Self::parse("")
+        }
+    }
+
+    pub fn save(&self, day_dir: &Path) -> Result<(), Box<dyn Error>> {
+        workspace::write_toml(&day_dir.join(HISTORY_FILE), &self.doc)
+    }
+
+    pub fn guesses(&self, part: u8) -> Vec<(String, Outcome)> {
+        let Some(table) = self.doc.get(&key(part)).and_then(Item::as_table) else {
+            return Vec::new();
+        };
+        table
+            .iter()
+            .filter_map(|(answer, outcome)| {
+                Some((answer.to_string(), Outcome::from_name(outcome.as_str()?)?))
+            })
+            .collect()
+    }
+
+    /// Why `answer` is certainly wrong or pointless to send, if it is.
+    pub fn refusal(&self, part: u8, answer: &str) -> Option<String> {
+        let guesses = self.guesses(part);
+        if let Some((correct, _)) = guesses.iter().find(|(_, o)| *o == Outcome::Correct) {
+            return Some(format!("part {} was already solved with {}", part, correct));
+        }
+        if let Some((_, outcome)) = guesses.iter().find(|(a, _)| a == answer) {
+            return Some(format!("{} was already tried and was {}", answer, outcome));
+        }
+
+        let number: i64 = answer.parse().ok()?;
+        let bound = |side: Outcome| {
+            guesses
+                .iter()
+                .filter(move |(_, o)| *o == side)
+                .filter_map(|(a, _)| a.parse::<i64>().ok())
+        };
+        if let Some(high) = bound(Outcome::TooHigh).min().filter(|h| number >= *h) {
+            return Some(format!("{} was too high, so {} is too", high, number));
+        }
+        if let Some(low) = bound(Outcome::TooLow).max().filter(|l| number <= *l) {
+            return Some(format!("{} was too low, so {} is too", low, number));
+        }
+        None
+    }
+
+    pub fn record(&mut self, part: u8, answer: &str, outcome: Outcome) {
+        if let Some(name) = outcome.name() {
+            let table = self.doc[&key(part)].or_insert(Item::Table(Table::new()));
+            table[answer] = value(name);
+        }
+    }
+}
+
+fn key(part: u8) -> String {
+    format!("part{}", part)
+}
+
+/// Keeps a correct answer in the day's `answers.toml`, so that it is checked
+/// from then on.
+fn record_answer(day_dir: &Path, part: u8, answer: &str) -> Result<(), Box<dyn Error>> {
+    let path = day_dir.join("answers.toml");
+    let mut doc = if path.exists() {
+        workspace::read_toml(&path)?
+    } else {
+        Document::new()
+    };
+    let puzzle = doc["puzzle"].or_insert(Item::Table(Table::new()));
+    puzzle[&key(part)] = value(answer);
+    workspace::write_toml(&path, &doc)
+}
+
+/// Sends an answer unless the history shows it can't be right, and records
+/// what came back.
+pub fn submit(
+    service: &dyn AnswerService,
+    day_dir: &Path,
+    year: u32,
+    day: u32,
+    part: u8,
+    answer: &str,
+) -> Result<Outcome, Box<dyn Error>> {
+    let answer = answer.trim();
+    let mut history = History::load(day_dir)?;
+    if let Some(reason) = history.refusal(part, answer) {
+        return Err(format!("not submitting: {}", reason).into());
+    }
+
+    let outcome = service.submit(year, day, part, answer)?;
+    history.record(part, answer, outcome);
+    history.save(day_dir)?;
+    if outcome == Outcome::Correct {
+        record_answer(day_dir, part, answer)?;
+    }
+    Ok(outcome)
+}
+
+#[cfg(test)]
+mod test {
+    use std::{cell::RefCell, error::Error, path::PathBuf, time::Duration};
+
+    use super::{parse_wait, submit, AnswerService, History, Outcome};
+
+    /// Replies with the outcomes it is given, in order.
+    struct Mock {
+        replies: RefCell<Vec<Outcome>>,
+        sent: RefCell<Vec<String>>,
+    }
+
+    impl Mock {
+        fn new(replies: &[Outcome]) -> Self {
+            Self {
+                replies: RefCell::new(replies.iter().rev().copied().collect()),
+                sent: RefCell::new(Vec::new()),
+            }
+        }
+    }
+
+    impl AnswerService for Mock {
+        fn submit(
+            &self,
+            _: u32,
+            _: u32,
+            part: u8,
+            answer: &str,
+        ) -> Result<Outcome, Box<dyn Error>> {
+            self.sent.borrow_mut().push(format!("{}:{}", part, answer));
+            Ok(self.replies.borrow_mut().pop().expect("no more replies"))
+        }
+    }
+
+    fn temp_dir() -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-prep-submit-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn outcomes() {
+        let wrong =
+            "<article><p>That's not the right answer; your answer is too high.</p></article>";
+        assert_eq!(Outcome::parse(wrong).unwrap(), Outcome::TooHigh);
+        assert_eq!(
+            Outcome::parse("<p>That's the right answer! You are one gold star closer.</p>")
+                .unwrap(),
+            Outcome::Correct
+        );
+        assert_eq!(
+            parse_wait("You gave an answer too recently. You have 4m 39s left to wait."),
+            Some(Duration::from_secs(279))
+        );
+        assert!(Outcome::parse("<p>Something else</p>").is_err());
+    }
+
+    #[test]
+    fn refusals() {
+        let history = History::parse(
+            "[part1]\n\"500\" = \"too high\"\n\"100\" = \"too low\"\n\"abc\" = \"wrong\"\n",
+        )
+        .unwrap();
+        assert!(history.refusal(1, "abc").is_some());
+        assert!(history.refusal(1, "500").is_some());
+        assert!(history.refusal(1, "600").is_some());
+        assert!(history.refusal(1, "50").is_some());
+        assert_eq!(history.refusal(1, "300"), None);
+        assert_eq!(history.refusal(1, "xyz"), None);
+        assert_eq!(history.refusal(2, "600"), None);
+    }
+
+    #[test]
+    fn records_guesses() {
+        let day_dir = temp_dir();
+        std::fs::write(day_dir.join("answers.toml"), "[sample]\npart1 = \"24\"\n").unwrap();
+        let mock = Mock::new(&[
+            Outcome::TooLow,
+            Outcome::Wait(Duration::from_secs(30)),
+            Outcome::Correct,
+        ]);
+
+        assert_eq!(
+            submit(&mock, &day_dir, 2022, 14, 1, "600").unwrap(),
+            Outcome::TooLow
+        );
+        assert!(submit(&mock, &day_dir, 2022, 14, 1, "599").is_err());
+        // A wait isn't a verdict on the answer, so it can be sent again
+        assert!(matches!(
+            submit(&mock, &day_dir, 2022, 14, 1, "618").unwrap(),
+            Outcome::Wait(_)
+        ));
+        assert_eq!(
+            submit(&mock, &day_dir, 2022, 14, 1, "618\n").unwrap(),
+            Outcome::Correct
+        );
+        assert!(submit(&mock, &day_dir, 2022, 14, 1, "700").is_err());
+        assert_eq!(*mock.sent.borrow(), ["1:600", "1:618", "1:618"]);
+
+        let history = History::load(&day_dir).unwrap();
+        assert_eq!(
+            history.guesses(1),
+            [
+                ("600".to_string(), Outcome::TooLow),
+                ("618".to_string(), Outcome::Correct)
+            ]
+        );
+        let answers = std::fs::read_to_string(day_dir.join("answers.toml")).unwrap();
+        assert_eq!(
+            answers,
+            "[sample]\npart1 = \"24\"\n\n[puzzle]\npart1 = \"618\"\n"
+        );
+
+        std::fs::remove_dir_all(&day_dir).unwrap();
+    }
+}
@@ -0,0 +1,250 @@
+use std::{
+    error::Error,
+    path::{Path, PathBuf},
+};
+
+use toml_edit::{Array, Document, Value};
+
+/// The member built when no package is selected, unless a day is focused.
+pub const RUNNER: &str = "tools/aoc-runner";
+
+/// The nearest `Cargo.toml` above the current directory with a `[workspace]`
+/// table.
+pub fn find_workspace() -> Result<PathBuf, Box<dyn Error>> {
+    for path in std::env::current_dir()?.ancestors() {
+        let cargo_toml = path.join("Cargo.toml");
+        if cargo_toml.exists() {
+            let doc = read_toml(&cargo_toml)?;
+            if doc.contains_key("workspace") {
+                return Ok(cargo_toml);
+            }
+        }
+    }
+    Err("no workspace Cargo.toml found above the current directory".into())
+}
+
+pub fn read_toml(path: &Path) -> Result<Document, Box<dyn Error>> {
+    let content = std::fs::read_to_string(path)?;
+    Ok(content.parse()?)
+}
+
+pub fn write_toml(path: &Path, doc: &Document) -> Result<(), Box<dyn Error>> {
+    std::fs::write(path, doc.to_string())?;
+    Ok(())
+}
+
+pub fn member_path(year: u32, day: u32) -> String {
+    format!("challenges/{}/day{}", year, day)
+}
+
+// The year and day of a challenge member, for ordering
+fn member_key(path: &str) -> Option<(u32, u32)> {
+    let (year, day) = path.strip_prefix("challenges/")?.split_once("/day")?;
+    Some((year.parse().ok()?, day.parse().ok()?))
+}
+
+/// The name of the package at `member`, which isn't always the one `new`
+/// would give it.
+pub fn package_name(root: &Path, member: &str) -> Result<String, Box<dyn Error>> {
+    let doc = read_toml(&root.join(member).join("Cargo.toml"))?;
+    Ok(doc["package"]["name"]
+        .as_str()
+        .ok_or_else(|| format!("{} has no package name", member))?
+        .to_string())
+}
+
+/// The year and day of every challenge on disk, whether or not it is in the
+/// workspace.
+pub fn challenges(root: &Path) -> Result<Vec<(u32, u32)>, Box<dyn Error>> {
+    let mut challenges = Vec::new();
+    for year in std::fs::read_dir(root.join("challenges"))? {
+        let year = year?;
+        let Some(y) = year.file_name().to_str().and_then(|y| y.parse().ok()) else {
+            continue;
+        };
+        for day in std::fs::read_dir(year.path())? {
+            let day = day?;
+            let d = day
+                .file_name()
+                .to_str()
+                .and_then(|d| d.strip_prefix("day")?.parse().ok());
+            if let Some(d) = d.filter(|_| day.path().join("Cargo.toml").exists()) {
+                challenges.push((y, d));
+            }
+        }
+    }
+    challenges.sort();
+    Ok(challenges)
+}
+
+fn array<'a>(doc: &'a Document, key: &str) -> Vec<&'a str> {
+    doc["workspace"][key]
+        .as_array()
+        .map(|a| a.iter().filter_map(Value::as_str).collect())
+        .unwrap_or_default()
+}
+
+fn array_mut<'a>(doc: &'a mut Document, key: &str) -> Result<&'a mut Array, Box<dyn Error>> {
+    Ok(doc["workspace"][key]
+        .as_array_mut()
+        .ok_or_else(|| format!("workspace.{} is not an array", key))?)
+}
+
+pub fn members(doc: &Document) -> Vec<&str> {
+    array(doc, "members")
+}
+
+pub fn default_members(doc: &Document) -> Vec<&str> {
+    array(doc, "default-members")
+}
+
+/// Adds `path` to the workspace members, next to the other challenges in
+/// year and day order. Returns false if it was already a member.
+pub fn add_member(doc: &mut Document, path: &str) -> Result<bool, Box<dyn Error>> {
+    let members = array_mut(doc, "members")?;
+    if members.iter().any(|m| m.as_str() == Some(path)) {
+        return Ok(false);
+    }
+
+    let key = member_key(path);
+    let mut index = members.len();
+    let mut last_challenge = None;
+    for (i, member) in members.iter().enumerate() {
+        if let Some(other) = member.as_str().and_then(member_key) {
+            last_challenge = Some(i);
+            if key.is_some() && Some(other) > key {
+                index = i;
+                break;
+            }
+        }
+    }
+    if index == members.len() {
+        if let Some(last) = last_challenge {
+            index = last + 1;
+        }
+    }
+
+    // Keep the one member per line layout of the root manifest
+    let value = Value::from(path).decorated("\n    ", "");
+    members.insert_formatted(index, value);
+    Ok(true)
+}
+
+/// Removes every occurrence of `path` from the workspace members. A comment
+/// above a removed entry stays, above the entry that followed it. Returns
+/// false if it wasn't a member.
+pub fn remove_member(doc: &mut Document, path: &str) -> Result<bool, Box<dyn Error>> {
+    let members = array_mut(doc, "members")?;
+    let mut removed = false;
+    loop {
+        let Some(index) = members.iter().position(|m| m.as_str() == Some(path)) else {
+            break;
+        };
+        let value = members.remove(index);
+        removed = true;
+
+        let prefix = value.decor().prefix().unwrap_or_default();
+        if prefix.contains('#') {
+            match members.get_mut(index) {
+                Some(next) => {
+                    let next_prefix = next.decor().prefix().unwrap_or_default();
+                    let joined = format!("{}{}", prefix.trim_end_matches([' ', '\n']), next_prefix);
+                    next.decor_mut().set_prefix(joined);
+                }
+                None => {
+                    let trailing = format!("{}{}", prefix.trim_end(), members.trailing());
+                    members.set_trailing(&trailing);
+                }
+            }
+        }
+    }
+    Ok(removed)
+}
+
+/// Makes `members` the packages built when none are selected.
+pub fn set_default_members(doc: &mut Document, members: &[&str]) -> Result<(), Box<dyn Error>> {
+    let default_members = array_mut(doc, "default-members")?;
+    default_members.clear();
+    for member in members {
+        default_members.push_formatted(Value::from(*member).decorated("\n    ", ""));
+    }
+    default_members.set_trailing("\n");
+    default_members.set_trailing_comma(false);
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use toml_edit::Document;
+
+    use super::{add_member, default_members, remove_member, set_default_members};
+
+    const MANIFEST: &str = r#"[workspace]
+members = [
+    "aoc",
+    "challenges/2021/day3",
+    "challenges/2022/day2",
+    "challenges/2022/day10",
+    "tools/aoc-prep",
+]
+"#;
+
+    #[test]
+    fn members_stay_in_day_order() {
+        let mut doc: Document = MANIFEST.parse().unwrap();
+        assert!(add_member(&mut doc, "challenges/2022/day9").unwrap());
+        assert!(add_member(&mut doc, "challenges/2022/day11").unwrap());
+        assert!(!add_member(&mut doc, "challenges/2022/day9").unwrap());
+
+        assert_eq!(
+            doc.to_string(),
+            r#"[workspace]
+members = [
+    "aoc",
+    "challenges/2021/day3",
+    "challenges/2022/day2",
+    "challenges/2022/day9",
+    "challenges/2022/day10",
+    "challenges/2022/day11",
+    "tools/aoc-prep",
+]
+"#
+        );
+    }
+
+    #[test]
+    fn members_toggle_and_keep_comments() {
+        let mut doc: Document = r#"[workspace]
+members = [
+    "aoc",
+    # Slow, see the notes in its README
+    "challenges/2022/day16",
+    "challenges/2022/day16",
+    "tools/aoc-prep",
+]
+default-members = [
+    "tools/aoc-runner"
+]
+"#
+        .parse()
+        .unwrap();
+        assert!(remove_member(&mut doc, "challenges/2022/day16").unwrap());
+        assert!(!remove_member(&mut doc, "challenges/2022/day16").unwrap());
+        set_default_members(&mut doc, &["challenges/2022/day9"]).unwrap();
+        assert_eq!(default_members(&doc), ["challenges/2022/day9"]);
+
+        assert_eq!(
+            doc.to_string(),
+            r#"[workspace]
+members = [
+    "aoc",
+    # Slow, see the notes in its README
+    "tools/aoc-prep",
+]
+default-members = [
+    "challenges/2022/day9"
+]
+"#
+        );
+    }
+}
@@ -0,0 +1,13 @@
+//! AOC {{year}} day {{day}}{{#if title}}: {{title}}{{/if}}
+
+pub fn part1(_input: &str) -> String {
+    String::new()
+}
+
+pub fn part2(_input: &str) -> String {
+    String::new()
+}
+
+aoc::solution!({{year}}, {{day}}, part1, part2);
+
+aoc::sample_tests!(sample);
@@ -0,0 +1,133 @@
+use std::{process::ExitCode, time::Duration};
+
+use aoc::{
+    answers::{self, Verdict, EACH_INPUT_KIND},
+    solution::{InputKind, Part, Solution, EACH_PART},
+};
+use clap::{Parser, Subcommand};
+
+mod registry;
+
+/// Runs the aoc challenges in the workspace
+#[derive(Parser, Debug)]
+#[command(author, version, about, long_about = None)]
+struct Args {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Run every challenge, or only those of a year or a single day
+    Run {
+        /// AOC puzzle year
+        year: Option<u32>,
+        /// AOC puzzle day
+        day: Option<u32>,
+        /// Only run this part
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Which input file to solve: sample or puzzle
+        #[arg(short, long, default_value_t = InputKind::Puzzle)]
+        input: InputKind,
+    },
+    /// Check both parts against both inputs and their recorded answers
+    Check {
+        /// AOC puzzle year
+        year: Option<u32>,
+        /// AOC puzzle day
+        day: Option<u32>,
+    },
+    /// List the registered challenges
+    List {
+        /// AOC puzzle year
+        year: Option<u32>,
+    },
+}
+
+fn selected(year: Option<u32>, day: Option<u32>) -> Vec<&'static dyn Solution> {
+    registry::solutions()
+        .into_iter()
+        .filter(|s| year.is_none_or(|y| s.year() == y))
+        .filter(|s| day.is_none_or(|d| s.day() == d))
+        .collect()
+}
+
+fn verify_all(
+    solutions: &[&'static dyn Solution],
+    kinds: &[InputKind],
+    parts: &[Part],
+) -> ExitCode {
+    if solutions.is_empty() {
+        eprintln!("No challenge matches");
+        return ExitCode::FAILURE;
+    }
+
+    let mut total = Duration::ZERO;
+    let mut counts = [0; 3];
+    let mut passed = true;
+    for solution in solutions {
+        let report = answers::verify(*solution, kinds, parts);
+        print!("{}", report);
+        passed &= report.passed();
+
+        total += report.checks.iter().map(|c| c.elapsed).sum::<Duration>();
+        for (count, verdict) in
+            counts
+                .iter_mut()
+                .zip([Verdict::Pass, Verdict::Fail, Verdict::Unknown])
+        {
+            *count += report.count(verdict);
+        }
+    }
+
+    println!();
+    println!(
+        "{} {}, {} {}, {} {} in {:.2?}",
+        counts[0],
+        Verdict::Pass,
+        counts[1],
+        Verdict::Fail,
+        counts[2],
+        Verdict::Unknown,
+        total
+    );
+    if passed {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn main() -> ExitCode {
+    let args = Args::parse();
+
+    match args.command {
+        Command::Run {
+            year,
+            day,
+            part,
+            input,
+        } => {
+            let parts: Vec<Part> = EACH_PART
+                .into_iter()
+                .filter(|p| part.is_none_or(|part| part == p.number()))
+                .collect();
+            verify_all(&selected(year, day), &[input], &parts)
+        }
+        Command::Check { year, day } => {
+            verify_all(&selected(year, day), &EACH_INPUT_KIND, &EACH_PART)
+        }
+        Command::List { year } => {
+            for solution in selected(year, None) {
+                println!(
+                    "{} day {:<2} {}",
+                    solution.year(),
+                    solution.day(),
+                    solution.directory()
+                );
+            }
+            ExitCode::SUCCESS
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use aoc::solution::Solution;
+
+/// Every challenge in the workspace, in year and day order.
+pub fn solutions() -> Vec<&'static dyn Solution> {
+    let mut solutions: Vec<&'static dyn Solution> = vec![
+        &aoc2021day1::SOLUTION,
+        &aoc2021day2::SOLUTION,
+        &aoc2021day3::SOLUTION,
+        &aoc2022day1::SOLUTION,
+        &aoc2022day2::SOLUTION,
+        &aoc2022day3::SOLUTION,
+        &aoc2022day4::SOLUTION,
+        &aoc2022day5::SOLUTION,
+        &aoc2022day6::SOLUTION,
+        &aoc2022day7::SOLUTION,
+        &aoc2022day8::SOLUTION,
+        &aoc2022day9::SOLUTION,
+        &aoc2022day10::SOLUTION,
+        &aoc2022day11::SOLUTION,
+        &aoc2022day12::SOLUTION,
+        &aoc2022day13::SOLUTION,
+        &aoc2022day14::SOLUTION,
+        &aoc2022day15::SOLUTION,
+        &aoc2022day16::SOLUTION,
+    ];
+    // aoc-prep appends the days it registers, so put them back in order
+    solutions.sort_by_key(|s| (s.year(), s.day()));
+    solutions
+}